@@ -1,14 +1,13 @@
 use clap::Parser;
-use serde::Deserialize;
-use serde_json::Value;
-use sharesight_reqwest::ClientCredentials;
-use sharesight_types::DEFAULT_API_HOST;
+use serde::{Deserialize, Serialize};
+use sharesight_reqwest::{ClientCredentials, Secret};
+use sharesight_types::{BaseUrl, DEFAULT_API_HOST};
 
 /// Auth with an OAuth2 Authorization Code using the Sharesight API
 #[derive(Parser, Debug)]
 #[clap(author, version, about, long_about = None)]
 struct Args {
-    /// The host to use to access the API.
+    /// The host to use to access the API, or a base url such as `http://localhost:8080`.
     #[clap(long, default_value = DEFAULT_API_HOST)]
     api_host: String,
     /// The redirect URI of the API application.
@@ -29,6 +28,7 @@ struct Args {
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let args = Args::parse();
+    let base_url = args.api_host.parse::<BaseUrl>()?;
     let client = reqwest::Client::new();
 
     let params = [
@@ -39,7 +39,7 @@ async fn main() -> anyhow::Result<()> {
         ("client_secret", &args.client_secret),
     ];
     let resp = client
-        .post(format!("https://{}/oauth2/token", args.api_host))
+        .post(base_url.oauth_token_url())
         .form(&params)
         .send()
         .await?;
@@ -47,9 +47,9 @@ async fn main() -> anyhow::Result<()> {
     if resp.status().is_success() {
         let auth = resp.json::<Auth>().await?;
 
-        println!("Access token: {}", auth.access_token);
+        println!("Access token: {}", auth.access_token.expose());
         if let Some(refresh_token) = &auth.refresh_token {
-            println!("Refresh token: {}", refresh_token);
+            println!("Refresh token: {}", refresh_token.expose());
         }
         println!("Expires in: {}s", auth.expires_in);
         println!("Created at: {}", auth.created_at);
@@ -63,23 +63,14 @@ async fn main() -> anyhow::Result<()> {
             std::fs::File::create(args.client_credentials_file)?,
             &client_credentials,
         )?;
-        let user_credentials = [
-            ("access_token", Value::String(auth.access_token)),
-            (
-                "id_token",
-                auth.refresh_token.map(Value::String).unwrap_or(Value::Null),
-            ),
-            ("lifetime", Value::Number(auth.expires_in.into())),
-            ("issued", Value::Number(auth.created_at.into())),
-            (
-                "stale",
-                Value::Number((auth.created_at + auth.expires_in as i64).into()),
-            ),
-            (
-                "expiry",
-                Value::Number((auth.created_at + 365 * 24 * 60 * 60).into()),
-            ),
-        ];
+        let user_credentials = UserCredentials {
+            access_token: &auth.access_token,
+            id_token: auth.refresh_token.as_ref(),
+            lifetime: auth.expires_in,
+            issued: auth.created_at,
+            stale: auth.created_at + auth.expires_in as i64,
+            expiry: auth.created_at + 365 * 24 * 60 * 60,
+        };
         serde_json::to_writer_pretty(
             std::fs::File::create(args.user_credentials_file)?,
             &user_credentials,
@@ -95,8 +86,18 @@ async fn main() -> anyhow::Result<()> {
 
 #[derive(Debug, Deserialize)]
 pub struct Auth {
-    pub access_token: String,
+    pub access_token: Secret,
     pub expires_in: u32,
-    pub refresh_token: Option<String>,
+    pub refresh_token: Option<Secret>,
     pub created_at: i64,
 }
+
+#[derive(Debug, Serialize)]
+pub struct UserCredentials<'a> {
+    pub access_token: &'a Secret,
+    pub id_token: Option<&'a Secret>,
+    pub lifetime: u32,
+    pub issued: i64,
+    pub stale: i64,
+    pub expiry: i64,
+}
@@ -1,7 +1,6 @@
 use clap::Parser;
 use log::info;
-use sharesight_examples::init_logger;
-use sharesight_reqwest::Client;
+use sharesight_examples::{init_logger, NetworkArgs};
 use sharesight_types::{
     CashAccountTransactionDelete, CashAccountTransactionDeleteParameters,
     CashAccountTransactionsList, CashAccountTransactionsListParameters,
@@ -20,6 +19,8 @@ struct Args {
     client_credentials_file: std::path::PathBuf,
     /// The access token to use the api.
     user_credentials_file: std::path::PathBuf,
+    #[clap(flatten)]
+    network: NetworkArgs,
 }
 
 #[tokio::main]
@@ -27,7 +28,11 @@ async fn main() -> anyhow::Result<()> {
     init_logger();
 
     let args = Args::parse();
-    let client = Client::new(args.user_credentials_file, args.client_credentials_file).await?;
+    let client = args
+        .network
+        .client_builder(args.user_credentials_file, args.client_credentials_file)?
+        .build()
+        .await?;
     let portfolio_name = args.portfolio_name;
     let cash_account_name = args.cash_account_name;
 
@@ -43,13 +48,7 @@ async fn main() -> anyhow::Result<()> {
         std::process::exit(0)
     });
 
-    let transactions_params = CashAccountTransactionsListParameters {
-        cash_account_id: cash_account.id,
-        from: None,
-        to: None,
-        description: None,
-        foreign_identifier: None,
-    };
+    let transactions_params = CashAccountTransactionsListParameters::new(cash_account.id);
     let CashAccountTransactionsListSuccess {
         cash_account_transactions,
         ..
@@ -60,7 +59,7 @@ async fn main() -> anyhow::Result<()> {
     for transaction in cash_account_transactions.into_iter() {
         info!("Deleting cash account transaction: {:?}", transaction);
 
-        let parameters = CashAccountTransactionDeleteParameters { id: transaction.id };
+        let parameters = CashAccountTransactionDeleteParameters::new(transaction.id);
 
         client
             .execute::<CashAccountTransactionDelete, ()>(&parameters)
@@ -1,11 +1,12 @@
 use chrono::{DateTime, FixedOffset};
 use clap::Parser;
-use sharesight_examples::init_logger;
-use sharesight_reqwest::Client;
+use sharesight_examples::{init_logger, NetworkArgs};
 use sharesight_types::{
-    CashAccountTransactionType, CashAccountTransactionTypeName, CashAccountTransactionsList,
+    CashAccountId, CashAccountTransactionId, CashAccountTransactionType,
+    CashAccountTransactionTypeName, CashAccountTransactionsList,
     CashAccountTransactionsListCashAccountTransactionsSuccess,
-    CashAccountTransactionsListParameters, CashAccountTransactionsListSuccess, Currency, Number,
+    CashAccountTransactionsListParameters, CashAccountTransactionsListSuccess, Currency, HoldingId,
+    Number, PayoutId, PortfolioId, TradeId,
 };
 
 /// List the portfolios using the Sharesight API
@@ -20,6 +21,8 @@ struct Args {
     client_credentials_file: std::path::PathBuf,
     /// The access token to use the api.
     user_credentials_file: std::path::PathBuf,
+    #[clap(flatten)]
+    network: NetworkArgs,
 }
 
 #[tokio::main]
@@ -30,7 +33,11 @@ async fn main() -> anyhow::Result<()> {
 
     log::info!("Running with args: {args:?}");
 
-    let client = Client::new(args.user_credentials_file, args.client_credentials_file).await?;
+    let client = args
+        .network
+        .client_builder(args.user_credentials_file, args.client_credentials_file)?
+        .build()
+        .await?;
     let portfolio_name = args.portfolio_name;
     let cash_account_name = args.cash_account_name;
 
@@ -46,13 +53,7 @@ async fn main() -> anyhow::Result<()> {
         std::process::exit(0)
     });
 
-    let transactions_params = CashAccountTransactionsListParameters {
-        cash_account_id: cash_account.id,
-        from: None,
-        to: None,
-        description: None,
-        foreign_identifier: None,
-    };
+    let transactions_params = CashAccountTransactionsListParameters::new(cash_account.id);
     let CashAccountTransactionsListSuccess {
         cash_account_transactions,
         ..
@@ -81,18 +82,18 @@ async fn main() -> anyhow::Result<()> {
     {
         #[derive(serde::Serialize)]
         pub struct TransactionRecord<'a> {
-            pub id: i64,
+            pub id: CashAccountTransactionId,
             pub account_name: &'a str,
-            pub portfolio_id: i64,
+            pub portfolio_id: PortfolioId,
             pub date_time: DateTime<FixedOffset>,
             pub currency: Currency,
             pub amount: Number,
             pub balance: Number,
-            pub cash_account_id: i64,
+            pub cash_account_id: CashAccountId,
             pub foreign_identifier: Option<String>,
-            pub holding_id: Option<i64>,
-            pub trade_id: Option<i64>,
-            pub payout_id: Option<i64>,
+            pub holding_id: Option<HoldingId>,
+            pub trade_id: Option<TradeId>,
+            pub payout_id: Option<PayoutId>,
             pub cash_account_transaction_type: CashAccountTransactionTypeName,
         }
 
@@ -101,7 +102,7 @@ async fn main() -> anyhow::Result<()> {
             account_name: &cash_account.name,
             portfolio_id: portfolio.id,
             date_time,
-            currency: cash_account.currency,
+            currency: cash_account.currency.clone(),
             amount,
             balance,
             cash_account_id,
@@ -1,6 +1,5 @@
 use clap::Parser;
-use sharesight_examples::init_logger;
-use sharesight_reqwest::Client;
+use sharesight_examples::{init_logger, NetworkArgs};
 use sharesight_types::{GroupsList, GroupsListSuccess};
 
 /// List the portfolios using the Sharesight API
@@ -11,6 +10,8 @@ struct Args {
     client_credentials_file: std::path::PathBuf,
     /// The access token to use the api.
     user_credentials_file: std::path::PathBuf,
+    #[clap(flatten)]
+    network: NetworkArgs,
 }
 
 #[tokio::main]
@@ -18,7 +19,11 @@ async fn main() -> anyhow::Result<()> {
     init_logger();
 
     let args = Args::parse();
-    let client = Client::new(args.user_credentials_file, args.client_credentials_file).await?;
+    let client = args
+        .network
+        .client_builder(args.user_credentials_file, args.client_credentials_file)?
+        .build()
+        .await?;
 
     let result = client.execute::<GroupsList, GroupsListSuccess>(&()).await?;
 
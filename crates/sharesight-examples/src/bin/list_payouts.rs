@@ -1,10 +1,10 @@
 use chrono::NaiveDate;
 use clap::Parser;
-use sharesight_examples::init_logger;
-use sharesight_reqwest::Client;
+use sharesight_examples::{init_logger, NetworkArgs};
 use sharesight_types::{
-    Currency, ListPortfolioPayouts, ListPortfolioPayoutsParameters,
-    ListPortfolioPayoutsPayoutsSuccess, ListPortfolioPayoutsSuccess, Market, Number,
+    Currency, HoldingId, InstrumentId, ListPortfolioPayouts, ListPortfolioPayoutsParameters,
+    ListPortfolioPayoutsPayoutsSuccess, ListPortfolioPayoutsSuccess, Market, Number, PayoutId,
+    PayoutState, PortfolioId,
 };
 
 /// List the portfolios using the Sharesight API
@@ -17,6 +17,8 @@ struct Args {
     client_credentials_file: std::path::PathBuf,
     /// The access token to use the api.
     user_credentials_file: std::path::PathBuf,
+    #[clap(flatten)]
+    network: NetworkArgs,
 }
 
 #[tokio::main]
@@ -24,7 +26,11 @@ async fn main() -> anyhow::Result<()> {
     init_logger();
 
     let args = Args::parse();
-    let client = Client::new(args.user_credentials_file, args.client_credentials_file).await?;
+    let client = args
+        .network
+        .client_builder(args.user_credentials_file, args.client_credentials_file)?
+        .build()
+        .await?;
     let portfolio_name = args.portfolio_name;
 
     let portfolios = client.build_portfolio_index().await?;
@@ -33,22 +39,17 @@ async fn main() -> anyhow::Result<()> {
         std::process::exit(0)
     });
 
-    let payouts_params = ListPortfolioPayoutsParameters {
-        portfolio_id: portfolio.id,
-        start_date: None,
-        end_date: None,
-        use_date: None,
-    };
+    let payouts_params = ListPortfolioPayoutsParameters::new(portfolio.id);
     let ListPortfolioPayoutsSuccess { payouts, .. } = client
         .execute::<ListPortfolioPayouts, _>(&payouts_params)
         .await?;
 
     #[derive(serde::Serialize)]
     struct PayoutRecord {
-        id: Option<i64>,
-        portfolio_id: i64,
-        holding_id: i64,
-        instrument_id: i64,
+        id: Option<PayoutId>,
+        portfolio_id: PortfolioId,
+        holding_id: HoldingId,
+        instrument_id: InstrumentId,
         symbol: String,
         market: Market,
         paid_on: NaiveDate,
@@ -64,7 +65,7 @@ async fn main() -> anyhow::Result<()> {
         comments: String,
         other_net_fsi: Option<Number>,
         company_event_id: Option<i64>,
-        state: String,
+        state: Option<PayoutState>,
         franked_amount: Option<Number>,
         unfranked_amount: Option<Number>,
         trust: Option<bool>,
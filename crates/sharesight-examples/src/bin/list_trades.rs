@@ -1,9 +1,8 @@
 use clap::Parser;
-use sharesight_examples::init_logger;
-use sharesight_reqwest::Client;
+use sharesight_examples::{init_logger, NetworkArgs};
 use sharesight_types::{
-    Currency, Market, Number, TradeDescription, Trades, TradesParameters, TradesSuccess,
-    TradesTradesSuccess,
+    Currency, HoldingId, InstrumentId, Market, Number, PortfolioId, TradeDescription, TradeId,
+    TradeState, Trades, TradesParameters, TradesSuccess, TradesTradesSuccess,
 };
 
 /// List the portfolios using the Sharesight API
@@ -16,6 +15,8 @@ struct Args {
     client_credentials_file: std::path::PathBuf,
     /// The access token to use the api.
     user_credentials_file: std::path::PathBuf,
+    #[clap(flatten)]
+    network: NetworkArgs,
 }
 
 #[tokio::main]
@@ -23,7 +24,11 @@ async fn main() -> anyhow::Result<()> {
     init_logger();
 
     let args = Args::parse();
-    let client = Client::new(args.client_credentials_file, args.user_credentials_file).await?;
+    let client = args
+        .network
+        .client_builder(args.client_credentials_file, args.user_credentials_file)?
+        .build()
+        .await?;
     let portfolio_name = args.portfolio_name;
 
     let portfolios = client.build_portfolio_index().await?;
@@ -32,19 +37,14 @@ async fn main() -> anyhow::Result<()> {
         std::process::exit(0)
     });
 
-    let trades_params = TradesParameters {
-        portfolio_id: portfolio.id.to_string(),
-        start_date: None,
-        end_date: None,
-        unique_identifier: None,
-    };
+    let trades_params = TradesParameters::new(portfolio.id);
     let TradesSuccess { trades, .. } = client
         .execute::<Trades, TradesSuccess>(&trades_params)
         .await?;
 
     #[derive(serde::Serialize)]
     pub struct TradesRecord {
-        pub id: Option<i64>,
+        pub id: Option<TradeId>,
         pub unique_identifier: Option<String>,
         pub transaction_date: chrono::NaiveDate,
         pub quantity: Number,
@@ -57,11 +57,11 @@ async fn main() -> anyhow::Result<()> {
         pub paid_on: Option<chrono::NaiveDate>,
         pub company_event_id: Option<i64>,
         pub comments: String,
-        pub portfolio_id: i64,
-        pub holding_id: i64,
-        pub state: String,
+        pub portfolio_id: PortfolioId,
+        pub holding_id: HoldingId,
+        pub state: Option<TradeState>,
         pub transaction_type: TradeDescription,
-        pub instrument_id: i64,
+        pub instrument_id: InstrumentId,
         pub symbol: String,
         pub market: Market,
         pub attachment_filename: Option<String>,
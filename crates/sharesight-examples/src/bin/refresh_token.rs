@@ -1,5 +1,5 @@
 use clap::Parser;
-use sharesight_reqwest::Client;
+use sharesight_examples::NetworkArgs;
 
 /// Auth with an OAuth2 Authorization Code using the Sharesight API
 #[derive(Parser, Debug)]
@@ -11,12 +11,18 @@ struct Args {
     client_credentials_file: std::path::PathBuf,
     /// The access token to use the api.
     user_credentials_file: std::path::PathBuf,
+    #[clap(flatten)]
+    network: NetworkArgs,
 }
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let args = Args::parse();
-    let _client = Client::new(args.user_credentials_file, args.client_credentials_file).await?;
+    let _client = args
+        .network
+        .client_builder(args.user_credentials_file, args.client_credentials_file)?
+        .build()
+        .await?;
 
     Ok(())
 }
@@ -1,12 +1,11 @@
 use std::num::NonZeroU32;
 
-use chrono::{prelude::*, Duration};
+use chrono::prelude::*;
 use clap::Parser;
-use sharesight_examples::init_logger;
-use sharesight_reqwest::Client;
+use sharesight_examples::{init_logger, NetworkArgs};
 use sharesight_types::{
-    GroupsList, GroupsListGroupsSuccess, GroupsListSuccess, PerformanceShow,
-    PerformanceShowParameters, PerformanceShowSuccess,
+    Grouping, GroupsList, GroupsListGroupsSuccess, GroupsListSuccess, PerformanceShow,
+    PerformanceShowParameters, PerformanceShowSuccess, ReportPeriod, ReportPeriodParameters,
 };
 
 /// Generate a 'performance' report using the sharesight API
@@ -32,6 +31,8 @@ struct Args {
     // Inception date to use, defaults to the earliest portfolio inception date.
     #[clap(long)]
     inception_date: Option<NaiveDate>,
+    #[clap(flatten)]
+    network: NetworkArgs,
 }
 
 #[tokio::main]
@@ -39,13 +40,17 @@ async fn main() -> anyhow::Result<()> {
     init_logger();
 
     let args = Args::parse();
-    let client = Client::new(args.user_credentials_file, args.client_credentials_file).await?;
+    let client = args
+        .network
+        .client_builder(args.user_credentials_file, args.client_credentials_file)?
+        .build()
+        .await?;
     let portfolio_names = args.portfolio_names;
     let group_name = args.group;
     let look_back_periods_in_years = args
         .look_back_periods_in_years
         .iter()
-        .map(|l| l.get() as i32)
+        .map(|l| l.get())
         .collect::<Vec<_>>();
 
     let portfolio_index = client.build_portfolio_index().await?;
@@ -65,12 +70,7 @@ async fn main() -> anyhow::Result<()> {
             .find_group(&group_name)
             .unwrap_or_else(|| std::process::exit(0))
     });
-    let custom_group_id = group.and_then(|g| g.id.id());
-    let grouping = if custom_group_id.is_some() {
-        Some("custom_group".to_string())
-    } else {
-        group.and_then(|g| g.id.name())
-    };
+    let grouping = group.map(Grouping::from);
 
     let today = Utc::now().date_naive();
     let inception_on = args.inception_date.unwrap_or_else(|| {
@@ -80,16 +80,15 @@ async fn main() -> anyhow::Result<()> {
             .min()
             .unwrap()
     });
-    let mut end_of_current_period = today.start_of_next_quarter() - Duration::days(1);
-    let mut start_of_current_periods = look_back_periods_in_years
+    let end_of_current_quarter = ReportPeriod::quarter(today).unwrap().end_date;
+    let mut current_periods = look_back_periods_in_years
         .iter()
         .copied()
         .map(|look_back_period_in_years| {
             (
                 look_back_period_in_years,
-                end_of_current_period
-                    .start_of_next_quarter()
-                    .add_years(-look_back_period_in_years),
+                ReportPeriod::years_ending(end_of_current_quarter, look_back_period_in_years)
+                    .expect("look back period out of range"),
             )
         })
         .collect::<Vec<_>>();
@@ -99,16 +98,9 @@ async fn main() -> anyhow::Result<()> {
 
         for portfolio in grouping.iter().flat_map(|_| portfolios.iter()).copied() {
             let performance_parameters = PerformanceShowParameters {
-                start_date: None,
-                end_date: None,
-                portfolio_id: portfolio.id,
                 consolidated: portfolio.consolidated,
-                include_sales: Some(true),
-                report_combined: None,
-                labels: None,
                 grouping: grouping.clone(),
-                custom_group_id,
-                include_limited: None,
+                ..PerformanceShowParameters::new(portfolio.id).include_sales(true)
             };
             let PerformanceShowSuccess {
                 report: performance_report,
@@ -131,20 +123,20 @@ async fn main() -> anyhow::Result<()> {
     };
 
     print!("End Date,Total");
-    for _ in 0..(start_of_current_periods.len() * portfolios.len() - 1) {
+    for _ in 0..(current_periods.len() * portfolios.len() - 1) {
         print!(",");
     }
 
     for title in &grouping_titles {
         print!(",{}", title);
-        for _ in 0..(start_of_current_periods.len() * portfolios.len() - 1) {
+        for _ in 0..(current_periods.len() * portfolios.len() - 1) {
             print!(",");
         }
     }
     println!();
 
     for _ in 0..(grouping_titles.len() + 1) {
-        for (period, _) in start_of_current_periods.iter() {
+        for (period, _) in current_periods.iter() {
             print!(",{} year", period);
             for _ in portfolios.iter().skip(1) {
                 print!(",");
@@ -153,37 +145,31 @@ async fn main() -> anyhow::Result<()> {
     }
     println!();
 
-    for _ in 0..((grouping_titles.len() + 1) * start_of_current_periods.len()) {
+    for _ in 0..((grouping_titles.len() + 1) * current_periods.len()) {
         for portfolio in &portfolios {
             print!(",{}", portfolio.name);
         }
     }
     println!();
 
-    while start_of_current_periods
+    while current_periods
         .iter()
-        .map(|(_, d)| d)
-        .copied()
+        .map(|(_, p)| p.start_date)
         .max()
         .unwrap()
         >= inception_on
     {
         let mut reports = Vec::new();
 
-        for (_, start_of_current_period) in start_of_current_periods.iter().copied() {
+        for (_, current_period) in current_periods.iter().copied() {
             for portfolio in &portfolios {
-                if start_of_current_period >= inception_on {
+                if current_period.start_date >= inception_on {
                     let performance_parameters = PerformanceShowParameters {
-                        start_date: Some(start_of_current_period),
-                        end_date: Some(end_of_current_period),
-                        portfolio_id: portfolio.id,
                         consolidated: portfolio.consolidated,
-                        include_sales: Some(true),
                         grouping: grouping.clone(),
-                        custom_group_id,
-                        include_limited: None,
-                        report_combined: None,
-                        labels: None,
+                        ..PerformanceShowParameters::new(portfolio.id)
+                            .report_period(current_period)
+                            .include_sales(true)
                     };
                     let PerformanceShowSuccess { report, .. } = client
                         .execute::<PerformanceShow, _>(&performance_parameters)
@@ -196,7 +182,7 @@ async fn main() -> anyhow::Result<()> {
             }
         }
 
-        print!("{}", end_of_current_period);
+        print!("{}", current_periods[0].1.end_date);
 
         for report in &reports {
             if let Some(report) = report {
@@ -222,59 +208,16 @@ async fn main() -> anyhow::Result<()> {
 
         println!();
 
-        for (_, start_of_current_period) in start_of_current_periods.iter_mut() {
-            *start_of_current_period = start_of_current_period.start_of_last_quarter();
+        for (_, current_period) in current_periods.iter_mut() {
+            *current_period = current_period
+                .shifted_by_months(-3)
+                .expect("report period out of range");
         }
-        end_of_current_period = end_of_current_period.end_of_last_quarter();
     }
 
     Ok(())
 }
 
-trait DateExt {
-    fn start_of_quarter(&self) -> Self;
-    fn start_of_next_quarter(&self) -> Self;
-    fn start_of_last_quarter(&self) -> Self;
-    fn end_of_last_quarter(&self) -> Self;
-    fn add_years(&self, years: i32) -> Self;
-}
-
-impl DateExt for NaiveDate {
-    fn start_of_quarter(&self) -> Self {
-        NaiveDate::from_ymd_opt(self.year(), self.month() - ((self.month() - 1) % 3), 1).unwrap()
-    }
-
-    fn start_of_next_quarter(&self) -> Self {
-        let start_of_quarter = self.start_of_quarter();
-        let (year, month, day) = (
-            start_of_quarter.year(),
-            start_of_quarter.month() + 3,
-            start_of_quarter.day(),
-        );
-        let (year, month) = if month > 12 {
-            (year + 1, month - 12)
-        } else {
-            (year, month)
-        };
-
-        NaiveDate::from_ymd_opt(year, month, day).unwrap()
-    }
-
-    fn start_of_last_quarter(&self) -> Self {
-        self.end_of_last_quarter().start_of_quarter()
-    }
-
-    fn end_of_last_quarter(&self) -> Self {
-        self.start_of_quarter() - Duration::days(1)
-    }
-
-    fn add_years(&self, years: i32) -> Self {
-        let (year, month, day) = (self.year() + years, self.month(), self.day());
-
-        NaiveDate::from_ymd_opt(year, month, day).unwrap()
-    }
-}
-
 trait GroupsListSuccessExt {
     fn find_group<'a>(&'a self, group_name: &str) -> Option<&'a GroupsListGroupsSuccess>;
 }
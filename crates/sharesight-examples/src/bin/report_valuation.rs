@@ -1,6 +1,5 @@
 use clap::Parser;
-use sharesight_examples::init_logger;
-use sharesight_reqwest::Client;
+use sharesight_examples::{init_logger, NetworkArgs};
 use sharesight_types::{Valuation, ValuationParameters, ValuationSuccess};
 
 /// Generate a 'valuation' report using the sharesight API
@@ -13,6 +12,8 @@ struct Args {
     client_credentials_file: std::path::PathBuf,
     /// The access token to use the api.
     user_credentials_file: std::path::PathBuf,
+    #[clap(flatten)]
+    network: NetworkArgs,
 }
 
 #[tokio::main]
@@ -20,7 +21,11 @@ async fn main() -> anyhow::Result<()> {
     init_logger();
 
     let args = Args::parse();
-    let client = Client::new(args.user_credentials_file, args.client_credentials_file).await?;
+    let client = args
+        .network
+        .client_builder(args.user_credentials_file, args.client_credentials_file)?
+        .build()
+        .await?;
     let portfolio_name = args.portfolio_name;
 
     let portfolios = client.build_portfolio_index().await?;
@@ -29,14 +34,7 @@ async fn main() -> anyhow::Result<()> {
         std::process::exit(0)
     });
 
-    let performance_parameters = ValuationParameters {
-        portfolio_id: portfolio.id,
-        consolidated: None,
-        include_sales: Some(true),
-        grouping: None,
-        custom_group_id: None,
-        balance_date: None,
-    };
+    let performance_parameters = ValuationParameters::new(portfolio.id).include_sales(true);
     let performance_report = client
         .execute::<Valuation, ValuationSuccess>(&performance_parameters)
         .await?;
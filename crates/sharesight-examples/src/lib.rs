@@ -1,4 +1,6 @@
-use std::env;
+use std::{env, path::PathBuf};
+
+use sharesight_reqwest::{Certificate, Client, ClientBuilder, Identity, Proxy};
 
 pub fn init_logger() {
     if Err(env::VarError::NotPresent) == env::var("RUST_LOG") {
@@ -7,3 +9,40 @@ pub fn init_logger() {
 
     env_logger::init();
 }
+
+/// Options for reaching the API from behind a proxy.
+#[derive(clap::Args, Debug)]
+pub struct NetworkArgs {
+    /// Proxy URL to send all requests through.
+    #[clap(long)]
+    pub proxy: Option<String>,
+    /// PEM file containing an additional root certificate to trust.
+    #[clap(long)]
+    pub ca_certificate: Vec<PathBuf>,
+    /// PEM file containing a client certificate and its private key.
+    #[clap(long)]
+    pub client_identity: Option<PathBuf>,
+}
+
+impl NetworkArgs {
+    pub fn client_builder(
+        self,
+        user_credentials_file: PathBuf,
+        client_credentials_file: PathBuf,
+    ) -> anyhow::Result<ClientBuilder> {
+        let mut builder = Client::builder(user_credentials_file, client_credentials_file);
+
+        if let Some(proxy) = self.proxy {
+            builder = builder.proxy(Proxy::all(proxy)?);
+        }
+        for ca_certificate in self.ca_certificate {
+            builder = builder
+                .add_root_certificate(Certificate::from_pem(&std::fs::read(ca_certificate)?)?);
+        }
+        if let Some(client_identity) = self.client_identity {
+            builder = builder.identity(Identity::from_pem(&std::fs::read(client_identity)?)?);
+        }
+
+        Ok(builder)
+    }
+}
@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use heck::ToUpperCamelCase;
 use indexmap::IndexMap;
 use serde::{
     de::{IntoDeserializer, Unexpected},
@@ -7,6 +8,8 @@ use serde::{
 };
 use serde_with::{formats::Separator, serde_as, StringWithSeparator};
 
+use crate::display::string_enum_type;
+
 #[derive(Debug, Deserialize)]
 pub struct ApiData {
     pub api: Vec<ApiEndpoint>,
@@ -18,13 +21,10 @@ pub struct ApiEndpoint {
     #[serde(rename = "type")]
     pub method: Method,
     pub url: String,
-    #[allow(dead_code)]
     pub title: String,
     pub name: String,
-    #[allow(dead_code)]
     pub group: String,
     pub version: String,
-    #[allow(dead_code)]
     pub description: String,
     #[allow(dead_code)]
     pub header: ApiHeaders,
@@ -51,10 +51,40 @@ impl ApiEndpoint {
             .collect::<Vec<_>>()
     }
 
+    /// The version segment of the endpoint's URL, e.g. `v2.1`, matching
+    /// `api_version_path` in `sharesight-types`.
+    pub fn api_version_path(&self) -> &'static str {
+        if self.version.starts_with("2.1.") {
+            "v2.1"
+        } else if self.version.starts_with("2.") {
+            "v2"
+        } else {
+            "v3"
+        }
+    }
+
+    /// The module the endpoint's version is generated in, e.g. `v2_1`.
+    pub fn module(&self) -> String {
+        self.api_version_path().replace('.', "_")
+    }
+
+    /// Endpoints with parameter fields containing `/` aren't generated.
+    pub fn has_unsupported_parameters(&self) -> bool {
+        self.parameter
+            .fields
+            .parameter
+            .iter()
+            .any(|f| f.field.iter().any(|s| s.contains('/')))
+    }
+
     pub fn fix(&mut self) {
         self.fix_url_params();
         self.fix_container_params();
         self.fix_dates();
+        self.fix_ids();
+        self.fix_grouping();
+        self.fix_enums();
+        self.fix_links();
     }
 
     fn fix_url_params(&mut self) {
@@ -105,16 +135,241 @@ impl ApiEndpoint {
     }
 
     fn fix_dates(&mut self) {
+        for field in self.parameter.fields.parameter.iter_mut() {
+            field.fix_date();
+        }
+
+        for field in self.success.all_fields_iter_mut() {
+            let is_string = field.field_type.is_string();
+            field.fix_date();
+
+            // Timestamps only recognised by their name, rather than their documented
+            // format, may be null or empty.
+            if is_string && field.field_type.is_date_time() && !field.is_documented_date_time() {
+                field.optional = true;
+            }
+        }
+    }
+
+    fn fix_ids(&mut self) {
+        let success_id_type = self.url_resource().and_then(resource_id_type);
+        let parameter_id_type = self.url_param_resource("id").and_then(resource_id_type);
+
+        for field in self.parameter.fields.parameter.iter_mut() {
+            if let Some(id_type) = field.id_type(parameter_id_type) {
+                field.field_type = FieldType::Scalar(FieldTypeBase::Id(id_type));
+            }
+        }
+
+        for field in self.success.all_fields_iter_mut() {
+            if let Some(id_type) = field.id_type(success_id_type) {
+                // Ids documented as strings are the ones that may be nil.
+                if field.field_type.is_string() {
+                    field.optional = true;
+                }
+                field.field_type = FieldType::Scalar(FieldTypeBase::Id(id_type));
+            }
+        }
+    }
+
+    /// String fields with documented values become enums named after the resource
+    /// that owns them, e.g. `TradeState` and `PayoutState`, so that unrelated fields
+    /// sharing a name don't share their values. Those in responses are optional.
+    fn fix_enums(&mut self) {
+        let endpoint_name = self.name.to_upper_camel_case();
+        let url_resource = self.url_resource().map(str::to_string);
+
+        let parameters = self
+            .parameter
+            .fields
+            .parameter
+            .iter_mut()
+            .map(|f| (f, false));
+        let success = self.success.all_fields_iter_mut().map(|f| (f, true));
+
+        for (field, is_success) in parameters.chain(success) {
+            let Some(field_name) = field.field.last() else {
+                continue;
+            };
+            if !matches!(field.field_type, FieldType::Scalar(FieldTypeBase::String))
+                || string_enum_type(field, &endpoint_name).is_some()
+            {
+                continue;
+            }
+
+            if let Some(values) = documented_values(&field.description) {
+                let owner = match &field.field[..] {
+                    [.., parent, _] => Some(parent.as_str()),
+                    _ => url_resource.as_deref(),
+                };
+                let enum_name = format!(
+                    "{}{}",
+                    owner
+                        .map(|owner| owner.strip_suffix('s').unwrap_or(owner))
+                        .unwrap_or_default()
+                        .to_upper_camel_case(),
+                    field_name.to_upper_camel_case()
+                );
+                field.field_type = FieldType::Scalar(FieldTypeBase::Enum(enum_name, values));
+                // Responses may have null in place of any string, which a missing
+                // value is more honest about than a made up one.
+                if is_success {
+                    field.optional = true;
+                }
+            }
+        }
+    }
+
+    /// Report parameters with both `grouping` and `custom_group_id` take a `Grouping`
+    /// in place of both, which serializes to the two fields.
+    fn fix_grouping(&mut self) {
+        let parameter_fields = &mut self.parameter.fields.parameter;
+        let is_top_level =
+            |f: &Field, name: &str| matches!(&f.field[..], [field_name] if field_name == name);
+
+        if !parameter_fields.iter().any(|f| is_top_level(f, "grouping"))
+            || !parameter_fields
+                .iter()
+                .any(|f| is_top_level(f, "custom_group_id"))
+        {
+            return;
+        }
+
+        parameter_fields.retain(|f| !is_top_level(f, "custom_group_id"));
+        for field in parameter_fields.iter_mut() {
+            if is_top_level(field, "grouping") {
+                field.field_type = FieldType::Scalar(FieldTypeBase::Grouping);
+                field.optional = true;
+            }
+        }
+    }
+
+    /// The URLs in `links` are typed as `Link`s so they can be followed.
+    fn fix_links(&mut self) {
         for field in self.success.all_fields_iter_mut() {
-            if matches!(field.field_type, FieldType::Scalar(FieldTypeBase::String))
-                && field
-                    .description
-                    .contains("(format <code>YYYY-MM-DD</code>)")
+            if field.field.iter().rev().skip(1).any(|s| s == "links")
+                && matches!(field.field_type, FieldType::Scalar(FieldTypeBase::String))
             {
-                field.field_type = FieldType::Scalar(FieldTypeBase::Date);
+                // Links that aren't returned may be null or empty.
+                field.field_type = FieldType::Scalar(FieldTypeBase::Link);
+                field.optional = true;
             }
         }
     }
+
+    /// The resource the endpoint returns, going by the last literal segment of its
+    /// URL, or the one before it if the URL ends in a parameter.
+    fn url_resource(&self) -> Option<&str> {
+        let segments = self.url_segments().collect::<Vec<_>>();
+
+        match segments[..] {
+            [.., resource, param] if is_url_param(param) => Some(resource),
+            [.., resource] if !is_url_param(resource) => Some(resource),
+            _ => None,
+        }
+    }
+
+    /// The resource identified by the URL parameter `param`.
+    fn url_param_resource(&self, param: &str) -> Option<&str> {
+        let segments = self.url_segments().collect::<Vec<_>>();
+
+        segments.windows(2).find_map(|window| match *window {
+            [resource, p] if is_url_param(p) && url_param_name(p) == param => Some(resource),
+            _ => None,
+        })
+    }
+
+    fn url_segments(&self) -> impl Iterator<Item = &str> {
+        self.url
+            .split('/')
+            .filter(|s| !s.is_empty())
+            .map(|s| s.split('.').next().unwrap_or_default())
+    }
+}
+
+/// The values a string field can take, if its description lists them in one of the
+/// forms the docs use, e.g. "can be any of `a`, `b` or `c`" or "Valid values: [a, b]".
+fn documented_values(description: &str) -> Option<Vec<String>> {
+    const MARKERS: &[&str] = &[
+        "can be any of",
+        "one of:",
+        "one of",
+        "valid values:",
+        "allowed values:",
+        "either",
+        "can be",
+    ];
+
+    let description = strip_html(description);
+    let lowercase = description.to_lowercase();
+    let start = MARKERS
+        .iter()
+        .find_map(|marker| lowercase.find(marker).map(|i| i + marker.len()))?;
+    let list = description[start..].trim_start();
+    let list = match list.strip_prefix('[') {
+        Some(list) => list.split(']').next()?,
+        None => list.split(['.', ')']).next()?,
+    };
+
+    let values = list
+        .split(',')
+        .flat_map(|s| s.split(" or "))
+        .flat_map(|s| s.split(" and "))
+        .map(|s| s.trim().trim_matches(['"', '\'']).to_string())
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>();
+
+    let is_identifier = |s: &String| {
+        s.chars().next().is_some_and(|c| c.is_ascii_alphabetic())
+            && s.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    };
+
+    (values.len() > 1 && values.iter().all(is_identifier)).then_some(values)
+}
+
+pub fn strip_html(s: &str) -> String {
+    let mut stripped = String::with_capacity(s.len());
+    let mut in_tag = false;
+
+    for c in s.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => stripped.push(c),
+            _ => {}
+        }
+    }
+
+    stripped.replace("&quot;", "\"")
+}
+
+fn is_url_param(segment: &str) -> bool {
+    segment.starts_with([':', '{'])
+}
+
+fn url_param_name(segment: &str) -> &str {
+    segment.trim_start_matches([':', '{']).trim_end_matches('}')
+}
+
+/// Resources that have an id newtype, and the name of that newtype.
+const ID_TYPES: &[(&str, &str)] = &[
+    ("cash_account", "CashAccountId"),
+    ("cash_account_transaction", "CashAccountTransactionId"),
+    ("custom_group", "CustomGroupId"),
+    ("holding", "HoldingId"),
+    ("instrument", "InstrumentId"),
+    ("payout", "PayoutId"),
+    ("portfolio", "PortfolioId"),
+    ("trade", "TradeId"),
+];
+
+fn resource_id_type(resource: &str) -> Option<&'static str> {
+    let resource = resource.strip_suffix('s').unwrap_or(resource);
+
+    ID_TYPES
+        .iter()
+        .find(|(r, _)| *r == resource)
+        .map(|(_, id_type)| *id_type)
 }
 
 pub fn group_fields_by_prefix(fields: &[Field]) -> IndexMap<&[String], Vec<&Field>> {
@@ -265,7 +520,7 @@ pub struct Example {
 }
 
 #[serde_as]
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Field {
     #[allow(dead_code)]
@@ -279,7 +534,73 @@ pub struct Field {
     pub description: String,
 }
 
-#[derive(Debug)]
+impl Field {
+    /// Whether a string field holds a date, going by its documented format.
+    fn is_date(&self) -> bool {
+        let description = self.description.to_lowercase();
+
+        (description.contains("yyyy-mm-dd") && !description.contains("yyyy-mm-ddt"))
+            || description.contains("dd mmm yyyy")
+    }
+
+    /// The id newtype for this field, inferred from its name (e.g. `holding_id`,
+    /// `trade_sync_cash_account_id`), or for an `id` field, from the struct it is
+    /// in, falling back to `id_type` for top level fields.
+    fn id_type(&self, id_type: Option<&'static str>) -> Option<&'static str> {
+        if !matches!(
+            self.field_type,
+            FieldType::Scalar(
+                FieldTypeBase::Integer | FieldTypeBase::String | FieldTypeBase::Number
+            )
+        ) {
+            return None;
+        }
+        // Report rows are identified by keys that are not always ids.
+        if self.description.contains("unique key") {
+            return None;
+        }
+
+        match &self.field[..] {
+            [.., parent, name] if name == "id" => resource_id_type(parent),
+            [name] if name == "id" => id_type,
+            [.., name] => ID_TYPES
+                .iter()
+                .find(|(r, _)| {
+                    name.strip_suffix("_id")
+                        .is_some_and(|n| n == *r || n.ends_with(&format!("_{}", r)))
+                })
+                .map(|(_, id_type)| *id_type),
+            [] => None,
+        }
+    }
+
+    fn fix_date(&mut self) {
+        if !matches!(self.field_type, FieldType::Scalar(FieldTypeBase::String)) {
+            return;
+        }
+
+        if self.is_date_time() {
+            self.field_type = FieldType::Scalar(FieldTypeBase::DateTime);
+        } else if self.is_date() {
+            self.field_type = FieldType::Scalar(FieldTypeBase::Date);
+        }
+    }
+
+    /// Whether a string field holds a timestamp, going by its name or documented format.
+    fn is_date_time(&self) -> bool {
+        let Some(name) = self.field.last() else {
+            return false;
+        };
+
+        name == "timestamp" || name.ends_with("_at") || self.is_documented_date_time()
+    }
+
+    fn is_documented_date_time(&self) -> bool {
+        self.description.contains("ISO 8601") || self.description.contains("ISO8601")
+    }
+}
+
+#[derive(Debug, Clone)]
 pub enum FieldType {
     Scalar(FieldTypeBase),
     Array(FieldTypeBase),
@@ -301,6 +622,18 @@ impl FieldType {
         )
     }
 
+    pub fn is_date_time(&self) -> bool {
+        matches!(self, FieldType::Scalar(FieldTypeBase::DateTime))
+    }
+
+    pub fn is_grouping(&self) -> bool {
+        matches!(self, FieldType::Scalar(FieldTypeBase::Grouping))
+    }
+
+    pub fn is_link(&self) -> bool {
+        matches!(self, FieldType::Scalar(FieldTypeBase::Link))
+    }
+
     pub fn is_string(&self) -> bool {
         matches!(
             self,
@@ -330,7 +663,7 @@ impl<'de> Deserialize<'de> for FieldType {
     }
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 pub enum FieldTypeBase {
     String,
     #[serde(alias = "Array")]
@@ -342,6 +675,18 @@ pub enum FieldTypeBase {
     DateTime,
     #[serde(rename = "")]
     Unit,
+    /// An id newtype, see `ApiEndpoint::fix_ids`.
+    #[serde(skip)]
+    Id(&'static str),
+    /// An enum of the values documented for a string field, see `ApiEndpoint::fix_enums`.
+    #[serde(skip)]
+    Enum(String, Vec<String>),
+    /// A URL in a response's `links`, see `ApiEndpoint::fix_links`.
+    #[serde(skip)]
+    Link,
+    /// A report's grouping and custom group, see `ApiEndpoint::fix_grouping`.
+    #[serde(skip)]
+    Grouping,
     File,
     Boolean,
     Group,
@@ -421,3 +766,108 @@ impl Separator for PeriodSeparator {
         "."
     }
 }
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use serde_json::json;
+
+    pub(crate) fn field(name: &str, field_type: &str, description: &str) -> serde_json::Value {
+        json!({
+            "group": "Success 200",
+            "type": field_type,
+            "optional": false,
+            "field": name,
+            "description": description,
+        })
+    }
+
+    pub(crate) fn endpoint(
+        parameters: Vec<serde_json::Value>,
+        success: Vec<serde_json::Value>,
+    ) -> ApiEndpoint {
+        let mut endpoint = serde_json::from_value::<ApiEndpoint>(json!({
+            "type": "get",
+            "url": "/things",
+            "title": "List things",
+            "name": "ThingsList",
+            "group": "Things",
+            "version": "3.0.0",
+            "description": "",
+            "header": { "fields": {}, "examples": [] },
+            "parameter": { "fields": { "Parameter": parameters } },
+            "success": { "fields": { "200 Success": success } },
+            "filename": "",
+            "groupTitle": "Things",
+        }))
+        .unwrap();
+        endpoint.fix();
+        endpoint
+    }
+
+    fn success_field<'a>(endpoint: &'a ApiEndpoint, name: &str) -> &'a Field {
+        endpoint
+            .success
+            .fields
+            .success
+            .iter()
+            .flatten()
+            .find(|f| f.field.join(".") == name)
+            .unwrap()
+    }
+
+    #[test]
+    fn dates_from_documented_format() {
+        let endpoint = endpoint(
+            vec![field("start_date", "String", "Start date (YYYY-MM-DD)")],
+            vec![
+                field("paid_on", "String", "Paid on (format YYYY-MM-DD)"),
+                field("traded", "String", "Traded on (dd mmm yyyy)"),
+                field("updated", "String", "Updated (see ISO 8601)"),
+                field("name", "String", "Name"),
+            ],
+        );
+
+        let start_date = &endpoint.parameter.fields.parameter[0];
+        assert!(matches!(
+            start_date.field_type,
+            FieldType::Scalar(FieldTypeBase::Date)
+        ));
+        assert!(matches!(
+            success_field(&endpoint, "paid_on").field_type,
+            FieldType::Scalar(FieldTypeBase::Date)
+        ));
+        assert!(matches!(
+            success_field(&endpoint, "traded").field_type,
+            FieldType::Scalar(FieldTypeBase::Date)
+        ));
+        assert!(success_field(&endpoint, "updated")
+            .field_type
+            .is_date_time());
+        assert!(!success_field(&endpoint, "updated").optional);
+        assert!(success_field(&endpoint, "name").field_type.is_string());
+    }
+
+    #[test]
+    fn date_times_from_name_are_optional() {
+        let endpoint = endpoint(
+            vec![field("created_at", "String", "Created after")],
+            vec![
+                field("api_transaction.timestamp", "String", "When it happened"),
+                field("created_at", "String", "When it was created"),
+                field("format", "String", "Output format"),
+            ],
+        );
+
+        let parameter = &endpoint.parameter.fields.parameter[0];
+        assert!(parameter.field_type.is_date_time());
+        assert!(!parameter.optional);
+
+        for name in ["api_transaction.timestamp", "created_at"] {
+            let field = success_field(&endpoint, name);
+            assert!(field.field_type.is_date_time(), "{}", name);
+            assert!(field.optional, "{}", name);
+        }
+        assert!(success_field(&endpoint, "format").field_type.is_string());
+    }
+}
@@ -4,24 +4,23 @@ use heck::ToUpperCamelCase;
 use indexmap::IndexMap;
 use log::{error, warn};
 
-use crate::api_data::{group_fields_by_prefix, ApiEndpoint, Field, FieldType, FieldTypeBase};
+use crate::{
+    api_data::{group_fields_by_prefix, strip_html, ApiEndpoint, Field, FieldType, FieldTypeBase},
+    shared::{SharedType, SharedTypeAlias, SharedTypes},
+};
 
-pub struct ApiEndpointStruct<'a>(pub &'a ApiEndpoint);
+pub struct ApiEndpointStruct<'a>(pub &'a ApiEndpoint, pub &'a SharedTypes);
 
 impl<'a> fmt::Display for ApiEndpointStruct<'a> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let Self(data) = self;
+        let Self(data, shared_types) = self;
 
-        let parameter_fields = &data.parameter.fields.parameter;
-        if parameter_fields
-            .iter()
-            .any(|f| f.field.iter().any(|s| s.contains('/')))
-        {
+        if data.has_unsupported_parameters() {
             warn!("Endpoint {} has parameter fields with / in them", data.name);
             return Ok(());
         }
 
-        let parameter_fields = group_fields_by_prefix(parameter_fields);
+        let parameter_fields = group_fields_by_prefix(&data.parameter.fields.parameter);
 
         let endpoint_name = data.name.to_upper_camel_case();
 
@@ -128,19 +127,316 @@ impl<'a> fmt::Display for ApiEndpointStruct<'a> {
 
         let success_fields = group_fields_by_prefix(data.success.api_fields());
 
-        writeln!(f, "{}", ApiStruct::success(&endpoint_name, &success_fields))?;
+        writeln!(
+            f,
+            "{}",
+            ApiStruct::success(&endpoint_name, &data.version, &success_fields, shared_types)
+        )?;
         writeln!(f)?;
 
+        for (field_name, trait_name) in [
+            ("api_transaction", "HasApiTransaction"),
+            ("links", "HasLinks"),
+        ] {
+            let Some(type_name) =
+                shared_types.get(&data.version, &endpoint_name, &[field_name.to_string()])
+            else {
+                continue;
+            };
+            let optional = data
+                .success
+                .api_fields()
+                .iter()
+                .find(|f| matches!(&f.field[..], [name] if name == field_name))
+                .is_some_and(|f| f.optional);
+
+            writeln!(f, "impl {} for {}Success {{", trait_name, endpoint_name)?;
+            writeln!(
+                f,
+                "    fn {}(&self) -> Option<&{}> {{",
+                field_name, type_name
+            )?;
+            if optional {
+                writeln!(f, "        self.{}.as_ref()", field_name)?;
+            } else {
+                writeln!(f, "        Some(&self.{})", field_name)?;
+            }
+            writeln!(f, "    }}")?;
+            writeln!(f, "}}")?;
+            writeln!(f)?;
+        }
+
+        fmt_paginated(f, data, shared_types, &endpoint_name)?;
+
         Ok(())
     }
 }
 
+/// Write a `Paginated` implementation for endpoints taking `page` and `per_page`
+/// parameters whose responses have `pagination` and a single list of items.
+fn fmt_paginated(
+    f: &mut fmt::Formatter<'_>,
+    data: &ApiEndpoint,
+    shared_types: &SharedTypes,
+    endpoint_name: &str,
+) -> fmt::Result {
+    fn top_level_field<'a>(fields: &'a [Field], name: &str) -> Option<&'a Field> {
+        fields
+            .iter()
+            .find(|f| matches!(&f.field[..], [field_name] if field_name == name))
+    }
+
+    let parameters = &data.parameter.fields.parameter;
+    let success = data.success.api_fields();
+    let (Some(_), Some(_), Some(pagination), Some(next_page)) = (
+        top_level_field(parameters, "page"),
+        top_level_field(parameters, "per_page"),
+        top_level_field(success, "pagination"),
+        success.iter().find(
+            |f| matches!(&f.field[..], [prefix, field_name] if prefix == "pagination" && field_name == "page"),
+        ),
+    ) else {
+        return Ok(());
+    };
+
+    let items = success
+        .iter()
+        .filter(|f| f.field.len() == 1 && f.field_type.is_array() && f.field_type.is_hash())
+        .collect::<Vec<_>>();
+    let [items] = items[..] else {
+        warn!(
+            "Endpoint {} is paginated but doesn't have exactly one list of items",
+            data.name
+        );
+        return Ok(());
+    };
+
+    let items_name = &items.field[0];
+    let item_type = shared_types
+        .get(&data.version, endpoint_name, &items.field)
+        .map(|t| t.to_string())
+        .unwrap_or_else(|| {
+            format!(
+                "{}{}Success",
+                endpoint_name,
+                items_name.to_upper_camel_case()
+            )
+        });
+
+    writeln!(f, "impl<'a> Paginated<'a> for {} {{", endpoint_name)?;
+    writeln!(f, "    type Item = {};", item_type)?;
+    writeln!(f)?;
+    writeln!(
+        f,
+        "    fn page(parameters: &Self::Parameters) -> Option<&str> {{"
+    )?;
+    writeln!(f, "        parameters.page.as_deref()")?;
+    writeln!(f, "    }}")?;
+    writeln!(f)?;
+    writeln!(
+        f,
+        "    fn set_page(parameters: &mut Self::Parameters, page: Option<String>) {{"
+    )?;
+    writeln!(f, "        parameters.page = page;")?;
+    writeln!(f, "    }}")?;
+    writeln!(f)?;
+    writeln!(
+        f,
+        "    fn per_page(parameters: &Self::Parameters) -> Option<i64> {{"
+    )?;
+    writeln!(f, "        parameters.per_page")?;
+    writeln!(f, "    }}")?;
+    writeln!(f)?;
+    writeln!(
+        f,
+        "    fn set_per_page(parameters: &mut Self::Parameters, per_page: Option<i64>) {{"
+    )?;
+    writeln!(f, "        parameters.per_page = per_page;")?;
+    writeln!(f, "    }}")?;
+    writeln!(f)?;
+    writeln!(
+        f,
+        "    fn next_page(success: &Self::Success) -> Option<&str> {{"
+    )?;
+    match (pagination.optional, next_page.optional) {
+        (false, false) => writeln!(
+            f,
+            "        Some(success.pagination.page.as_str()).filter(|page| !page.is_empty())"
+        )?,
+        (false, true) => writeln!(
+            f,
+            "        success.pagination.page.as_deref().filter(|page| !page.is_empty())"
+        )?,
+        (true, false) => writeln!(
+            f,
+            "        success.pagination.as_ref().map(|p| p.page.as_str()).filter(|page| !page.is_empty())"
+        )?,
+        (true, true) => writeln!(
+            f,
+            "        success.pagination.as_ref().and_then(|p| p.page.as_deref()).filter(|page| !page.is_empty())"
+        )?,
+    }
+    writeln!(f, "    }}")?;
+    writeln!(f)?;
+    writeln!(
+        f,
+        "    fn items(success: &Self::Success) -> &[Self::Item] {{"
+    )?;
+    if items.optional {
+        writeln!(
+            f,
+            "        success.{}.as_deref().unwrap_or_default()",
+            items_name
+        )?;
+    } else {
+        writeln!(f, "        &success.{}", items_name)?;
+    }
+    writeln!(f, "    }}")?;
+    writeln!(f)?;
+    writeln!(
+        f,
+        "    fn into_items(success: Self::Success) -> Vec<Self::Item> {{"
+    )?;
+    if items.optional {
+        writeln!(f, "        success.{}.unwrap_or_default()", items_name)?;
+    } else {
+        writeln!(f, "        success.{}", items_name)?;
+    }
+    writeln!(f, "    }}")?;
+    writeln!(f, "}}")?;
+    writeln!(f)?;
+
+    Ok(())
+}
+
+/// Re-exports the items of an endpoint from its version's module.
+pub struct ApiEndpointReExport<'a>(pub &'a ApiEndpoint, pub &'a SharedTypes);
+
+impl<'a> fmt::Display for ApiEndpointReExport<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self(data, shared_types) = *self;
+
+        if data.has_unsupported_parameters() {
+            return Ok(());
+        }
+
+        let endpoint_name = data.name.to_upper_camel_case();
+        let mut deprecated = false;
+        let mut struct_names = |fields, tag| {
+            group_fields_by_prefix(fields)
+                .into_keys()
+                .map(|prefix| {
+                    deprecated |= tag == "Success"
+                        && shared_types
+                            .get(&data.version, &endpoint_name, prefix)
+                            .is_some();
+
+                    format!(
+                        "{}{}{}",
+                        endpoint_name,
+                        prefix
+                            .iter()
+                            .map(|s| s.to_upper_camel_case())
+                            .collect::<String>(),
+                        tag
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let mut names = vec![endpoint_name.clone()];
+        if !data.url_params().is_empty() {
+            names.push(format!("{}UrlDisplay", endpoint_name));
+        }
+        names.extend(struct_names(&data.parameter.fields.parameter, "Parameters"));
+        names.extend(struct_names(data.success.api_fields(), "Success"));
+
+        // Shared type aliases are deprecated for users, not for re-exporting them.
+        if deprecated {
+            writeln!(f, "#[allow(deprecated)]")?;
+        }
+        writeln!(f, "pub use {}::{{{}}};", data.module(), names.join(", "))
+    }
+}
+
+/// The `ENDPOINTS` registry, listing the endpoints of one API version, or with no
+/// version, the current version of each endpoint re-exported at the crate root.
+pub struct EndpointRegistry<'a>(pub &'a [&'a ApiEndpoint], pub Option<&'a str>);
+
+impl<'a> fmt::Display for EndpointRegistry<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self(api_endpoints, version) = self;
+
+        match version {
+            Some(version) => writeln!(
+                f,
+                "/// The endpoints of version {} of the API, for tools that work with any endpoint.",
+                version
+            )?,
+            None => writeln!(
+                f,
+                "/// The current version of every endpoint, as re-exported at the crate root, for tools\n/// that work with any endpoint. Each version's module has its own `ENDPOINTS`."
+            )?,
+        }
+        writeln!(f, "pub const ENDPOINTS: &[EndpointInfo] = &[")?;
+        for data in api_endpoints
+            .iter()
+            .filter(|data| !data.has_unsupported_parameters())
+        {
+            let endpoint_name = data.name.to_upper_camel_case();
+            let parameters = if data.parameter.fields.parameter.is_empty() {
+                "()".to_string()
+            } else {
+                format!("{}Parameters", endpoint_name)
+            };
+
+            writeln!(f, "    EndpointInfo {{")?;
+            writeln!(f, "        name: {:?},", endpoint_name)?;
+            writeln!(f, "        title: {:?},", data.title)?;
+            writeln!(f, "        group: {:?},", data.group)?;
+            writeln!(
+                f,
+                "        description: {:?},",
+                plain_text(&data.description)
+            )?;
+            writeln!(f, "        url_path: {}::URL_PATH,", endpoint_name)?;
+            writeln!(f, "        http_method: {}::HTTP_METHOD,", endpoint_name)?;
+            writeln!(f, "        version: {}::VERSION,", endpoint_name)?;
+            writeln!(f, "        #[cfg(feature = \"schemars\")]")?;
+            writeln!(
+                f,
+                "        parameters_schema: || schemars::schema_for!({}),",
+                parameters
+            )?;
+            writeln!(f, "    }},")?;
+        }
+        writeln!(f, "];")
+    }
+}
+
+/// The text of an HTML description, with a line per paragraph, heading or list item.
+fn plain_text(html: &str) -> String {
+    let html = ["</p>", "</h3>", "</h5>", "</li>", "<br>", "<br/>", "<br />"]
+        .iter()
+        .fold(html.to_string(), |html, tag| html.replace(tag, "\n"));
+
+    strip_html(&html)
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 pub struct ApiStruct<'a> {
     tag: &'a str,
     label: &'a str,
     endpoint_name: &'a str,
+    version: &'a str,
     fields: &'a IndexMap<&'a [std::string::String], Vec<&'a Field>>,
     derives: &'a [&'static str],
+    builder: bool,
+    shared_types: Option<&'a SharedTypes>,
 }
 
 impl<'a> ApiStruct<'a> {
@@ -152,21 +448,29 @@ impl<'a> ApiStruct<'a> {
             tag: "Parameters",
             label: "parameter",
             endpoint_name,
+            version: "",
             fields,
-            derives: &["Serialize"],
+            derives: &["Serialize", "Deserialize"],
+            builder: true,
+            shared_types: None,
         }
     }
 
     fn success(
         endpoint_name: &'a str,
+        version: &'a str,
         fields: &'a IndexMap<&'a [std::string::String], Vec<&'a Field>>,
+        shared_types: &'a SharedTypes,
     ) -> Self {
         ApiStruct {
             tag: "Success",
             label: "success",
             endpoint_name,
+            version,
             fields,
-            derives: &["Deserialize"],
+            derives: &["Deserialize", "Serialize"],
+            builder: false,
+            shared_types: Some(shared_types),
         }
     }
 }
@@ -175,144 +479,557 @@ impl<'a> fmt::Display for ApiStruct<'a> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let ApiStruct {
             tag,
-            label,
             endpoint_name,
             fields,
-            derives,
+            shared_types,
+            ..
         } = *self;
 
         for (prefix, fields) in fields.iter() {
-            write!(f, "#[serde_as]")?;
-            write!(f, "#[derive(Debug, Clone")?;
-            for derive in derives {
-                write!(f, ", {}", derive)?;
-            }
-            writeln!(f, ")]")?;
-            write!(f, "pub struct {}", endpoint_name)?;
-            for prefix_segment in prefix.iter() {
-                write!(f, "{}", prefix_segment.to_upper_camel_case())?;
-            }
-            writeln!(f, "{} {{", tag)?;
-            for parameter in fields {
-                if let [ref prefix_segments @ .., ref field_name] = parameter.field[..] {
-                    if field_name == "supported_denominations" {
-                        continue;
+            let struct_name = format!(
+                "{}{}{}",
+                endpoint_name,
+                prefix
+                    .iter()
+                    .map(|s| s.to_upper_camel_case())
+                    .collect::<String>(),
+                tag
+            );
+
+            if let Some(type_name) =
+                shared_types.and_then(|s| s.get(self.version, endpoint_name, prefix))
+            {
+                write!(
+                    f,
+                    "{}",
+                    SharedTypeAlias {
+                        alias: &struct_name,
+                        type_name
                     }
+                )?;
+            } else {
+                self.fmt_struct(f, &struct_name, fields)?;
+            }
+        }
 
-                    write!(f, "{}", DocComment(&parameter.description))?;
+        Ok(())
+    }
+}
 
-                    if field_name == "self" {
-                        writeln!(f, "    #[serde(rename = \"self\")]")?;
-                    }
+impl<'a> ApiStruct<'a> {
+    fn fmt_struct(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        struct_name: &str,
+        fields: &[&Field],
+    ) -> fmt::Result {
+        let ApiStruct {
+            tag,
+            label,
+            endpoint_name,
+            version: _,
+            fields: _,
+            derives,
+            builder,
+            shared_types: _,
+        } = *self;
 
-                    if matches!(
-                        parameter.field_type,
-                        FieldType::Scalar(FieldTypeBase::Integer)
-                    ) {
-                        if parameter.optional {
-                            writeln!(
-                                f,
-                                "    #[serde_as(as = \"Option<PickFirst<(_, DisplayFromStr)>>\")]"
-                            )?;
-                        } else {
-                            writeln!(
-                                f,
-                                "    #[serde_as(as = \"PickFirst<(_, DisplayFromStr)>\")]"
-                            )?;
-                        }
-                    }
+        let all_optional = fields.iter().all(|field| field.optional);
 
-                    if matches!(parameter.field_type, FieldType::Scalar(FieldTypeBase::Date)) {
-                        if parameter.optional {
-                            writeln!(f, "    #[serde_as(as = \"Option<DeserializeDate>\")]")?;
-                        } else {
-                            writeln!(f, "    #[serde_as(as = \"DeserializeDate\")]")?;
-                        }
-                    }
+        write!(f, "#[serde_as]")?;
+        write!(f, "#[derive(Debug, Clone")?;
+        if builder && all_optional {
+            write!(f, ", Default")?;
+        }
+        for derive in derives {
+            write!(f, ", {}", derive)?;
+        }
+        writeln!(f, ")]")?;
+        writeln!(
+            f,
+            "#[cfg_attr(feature = \"schemars\", derive(schemars::JsonSchema))]"
+        )?;
+        writeln!(
+            f,
+            "#[cfg_attr(feature = \"arbitrary\", derive(arbitrary::Arbitrary))]"
+        )?;
+        writeln!(f, "pub struct {} {{", struct_name)?;
+        for parameter in fields {
+            if let [ref prefix_segments @ .., ref field_name] = parameter.field[..] {
+                if field_name == "supported_denominations" {
+                    continue;
+                }
 
-                    if matches!(
-                        parameter.field_type,
-                        FieldType::Scalar(FieldTypeBase::Number)
-                    ) {
-                        if parameter.optional {
-                            writeln!(f, "    #[serde_as(as = \"Option<DeserializeNumber>\")]")?;
-                        } else {
-                            writeln!(f, "    #[serde_as(as = \"DeserializeNumber\")]")?;
-                        }
-                    }
+                write!(f, "{}", DocComment(&parameter.description))?;
 
-                    if parameter.optional {
-                        writeln!(f, "    #[serde(default)]")?;
-                    } else if parameter.field_type.is_string()
-                        && string_enum_type(field_name, endpoint_name).is_none()
-                    {
-                        writeln!(f, "    #[serde(default)]")?;
-                        writeln!(f, "    #[serde_as(deserialize_as = \"DefaultOnNull\")]")?;
-                    }
+                if field_name == "self" {
+                    writeln!(f, "    #[serde(rename = \"self\")]")?;
+                }
 
-                    write!(
-                        f,
-                        "    pub {}: ",
-                        if field_name == "self" {
-                            "itself"
-                        } else if field_name == "type" {
-                            "r#type"
-                        } else {
-                            field_name
-                        }
-                    )?;
+                if matches!(
+                    parameter.field_type,
+                    FieldType::Scalar(FieldTypeBase::Integer)
+                ) {
                     if parameter.optional {
-                        write!(f, "Option<")?;
-                    }
-                    if parameter.field_type.is_hash() {
-                        if parameter.field_type.is_array() {
-                            write!(f, "Vec<")?;
-                        }
-                        if field_name == "cash_account_transaction_type" {
-                            write!(f, "CashAccountTransactionType")?;
-                        } else {
-                            write!(f, "{}", endpoint_name)?;
-                            for prefix_segment in prefix_segments.iter() {
-                                write!(f, "{}", prefix_segment.to_upper_camel_case())?;
-                            }
-                            write!(f, "{}{}", field_name.to_upper_camel_case(), tag)?;
-                        }
-                        if parameter.field_type.is_array() {
-                            write!(f, ">")?;
-                        }
-                    } else if parameter.field_type.is_string()
-                        && string_enum_type(field_name, endpoint_name).is_some()
-                    {
-                        write!(
+                        writeln!(
                             f,
-                            "{}",
-                            string_enum_type(field_name, endpoint_name).unwrap()
+                            "    #[serde_as(as = \"Option<PickFirst<(_, DisplayFromStr)>>\")]"
                         )?;
                     } else {
-                        write!(f, "{}", FieldTypeRustTypeNameDisplay(&parameter.field_type))?;
+                        writeln!(
+                            f,
+                            "    #[serde_as(as = \"PickFirst<(_, DisplayFromStr)>\")]"
+                        )?;
                     }
+                }
+
+                if matches!(parameter.field_type, FieldType::Scalar(FieldTypeBase::Date)) {
                     if parameter.optional {
-                        write!(f, ">")?;
+                        writeln!(f, "    #[serde_as(as = \"Option<DeserializeDate>\")]")?;
+                    } else {
+                        writeln!(f, "    #[serde_as(as = \"DeserializeDate\")]")?;
                     }
-                    writeln!(f, ",")?;
-                } else {
-                    error!(
-                        "Endpoint {} has {} field with no field name: {:?} ",
-                        endpoint_name, label, parameter
-                    );
                 }
+
+                if parameter.optional
+                    && (parameter.field_type.is_date_time() || parameter.field_type.is_link())
+                {
+                    writeln!(f, "    #[serde_as(as = \"DeserializeOptional\")]")?;
+                }
+
+                if matches!(
+                    parameter.field_type,
+                    FieldType::Scalar(FieldTypeBase::Number)
+                ) {
+                    if parameter.optional {
+                        writeln!(f, "    #[serde_as(as = \"Option<DeserializeNumber>\")]")?;
+                    } else {
+                        writeln!(f, "    #[serde_as(as = \"DeserializeNumber\")]")?;
+                    }
+                }
+
+                let arbitrary_with = match parameter.field_type {
+                    FieldType::Scalar(FieldTypeBase::Number) => Some("number"),
+                    FieldType::Scalar(FieldTypeBase::Date) => Some("date"),
+                    FieldType::Scalar(FieldTypeBase::DateTime) => Some("date_time"),
+                    _ => None,
+                };
+                if let Some(arbitrary_with) = arbitrary_with {
+                    writeln!(
+                        f,
+                        "    #[cfg_attr(feature = \"arbitrary\", arbitrary(with = arbitrary_{}{}))]",
+                        if parameter.optional { "optional_" } else { "" },
+                        arbitrary_with
+                    )?;
+                }
+
+                if parameter.field_type.is_grouping() {
+                    writeln!(
+                        f,
+                        "    #[serde(flatten, deserialize_with = \"deserialize_optional_grouping\")]"
+                    )?;
+                } else if parameter.optional {
+                    writeln!(f, "    #[serde(default)]")?;
+                } else if parameter.field_type.is_string()
+                    && string_enum_type(parameter, endpoint_name).is_none()
+                {
+                    writeln!(f, "    #[serde(default)]")?;
+                    writeln!(f, "    #[serde_as(deserialize_as = \"DefaultOnNull\")]")?;
+                }
+
+                writeln!(
+                    f,
+                    "    pub {}: {},",
+                    field_rust_name(field_name),
+                    self.field_rust_type(parameter, prefix_segments, field_name)
+                )?;
+            } else {
+                error!(
+                    "Endpoint {} has {} field with no field name: {:?} ",
+                    endpoint_name, label, parameter
+                );
             }
+        }
+        if tag == "Success" {
+            writeln!(
+                f,
+                "    #[cfg(any(feature = \"strict\", feature = \"extra_fields\"))]"
+            )?;
+            writeln!(f, "    #[serde(flatten)]")?;
+            writeln!(
+                f,
+                "    #[cfg_attr(feature = \"strict\", serde(deserialize_with = \"deny_extra_fields\"))]"
+            )?;
+            writeln!(
+                f,
+                "    #[cfg_attr(feature = \"arbitrary\", arbitrary(default))]"
+            )?;
+            writeln!(
+                f,
+                "    pub extra: serde_json::Map<String, serde_json::Value>,"
+            )?;
+        }
+        writeln!(f, "}}")?;
+        writeln!(f)?;
+
+        if tag == "Success" {
+            writeln!(f, "#[cfg(feature = \"extra_fields\")]")?;
+            writeln!(f, "impl ExtraFields for {} {{", struct_name)?;
+            writeln!(
+                f,
+                "    fn extra_fields(&self) -> &serde_json::Map<String, serde_json::Value> {{"
+            )?;
+            writeln!(f, "        &self.extra")?;
+            writeln!(f, "    }}")?;
+            writeln!(f)?;
+            writeln!(
+                f,
+                "    fn extra_fields_mut(&mut self) -> &mut serde_json::Map<String, serde_json::Value> {{"
+            )?;
+            writeln!(f, "        &mut self.extra")?;
+            writeln!(f, "    }}")?;
+            writeln!(f, "}}")?;
+            writeln!(f)?;
+
+            writeln!(f, "#[cfg(feature = \"strict\")]")?;
+            writeln!(f, "impl UnknownFields for {} {{", struct_name)?;
+            self.fmt_visit_extra_fields(f, fields)?;
             writeln!(f, "}}")?;
             writeln!(f)?;
         }
 
+        if builder {
+            self.fmt_builder(f, struct_name, fields)?;
+            self.fmt_report_period(f, struct_name, fields)?;
+        }
+        self.fmt_money_accessors(f, struct_name, fields)?;
+
+        Ok(())
+    }
+
+    /// Write an `UnknownFields::visit_extra_fields` implementation that also visits
+    /// the success structs nested in this one.
+    fn fmt_visit_extra_fields(&self, f: &mut fmt::Formatter<'_>, fields: &[&Field]) -> fmt::Result {
+        let nested = fields
+            .iter()
+            .filter(|field| field.field_type.is_hash())
+            .filter_map(|field| match field.field[..] {
+                [.., ref field_name]
+                    if field_name != "cash_account_transaction_type"
+                        && field_name != "supported_denominations" =>
+                {
+                    Some((*field, field_name))
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        writeln!(
+            f,
+            "    fn visit_extra_fields(&self, path: &str, visit: &mut dyn FnMut(&str, &serde_json::Map<String, serde_json::Value>)) {{"
+        )?;
+        writeln!(f, "        visit(path, &self.extra);")?;
+        for (field, field_name) in nested {
+            let rust_name = field_rust_name(field_name);
+            let path = if field.field_type.is_array() {
+                format!("{}[]", field_name)
+            } else {
+                field_name.to_string()
+            };
+            let each = match (field.optional, field.field_type.is_array()) {
+                (false, false) => {
+                    writeln!(
+                        f,
+                        "        self.{}.visit_extra_fields(&extra_field_path(path, \"{}\"), visit);",
+                        rust_name, path
+                    )?;
+                    continue;
+                }
+                (false, true) => format!("for item in &self.{}", rust_name),
+                (true, false) => format!("if let Some(item) = &self.{}", rust_name),
+                (true, true) => format!("for item in self.{}.iter().flatten()", rust_name),
+            };
+            writeln!(f, "        {} {{", each)?;
+            writeln!(
+                f,
+                "            item.visit_extra_fields(&extra_field_path(path, \"{}\"), visit);",
+                path
+            )?;
+            writeln!(f, "        }}")?;
+        }
+        writeln!(f, "    }}")
+    }
+
+    /// Write a `ReportPeriodParameters` implementation for parameters with optional
+    /// `start_date` and `end_date` dates.
+    fn fmt_report_period(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        struct_name: &str,
+        fields: &[&Field],
+    ) -> fmt::Result {
+        let is_optional_date = |name: &str| {
+            fields.iter().any(|f| {
+                matches!(&f.field[..], [field_name] if field_name == name)
+                    && f.optional
+                    && matches!(f.field_type, FieldType::Scalar(FieldTypeBase::Date))
+            })
+        };
+        if !is_optional_date("start_date") || !is_optional_date("end_date") {
+            return Ok(());
+        }
+
+        writeln!(f, "impl ReportPeriodParameters for {} {{", struct_name)?;
+        writeln!(
+            f,
+            "    fn set_report_period(&mut self, period: ReportPeriod) {{"
+        )?;
+        writeln!(f, "        self.start_date = Some(period.start_date);")?;
+        writeln!(f, "        self.end_date = Some(period.end_date);")?;
+        writeln!(f, "    }}")?;
+        writeln!(f, "}}")?;
+        writeln!(f)?;
+
         Ok(())
     }
+
+    /// Write a `Money` accessor for each amount that has a currency field alongside
+    /// it, e.g. `brokerage` and `brokerage_currency_code`, or `amount` and `currency`.
+    fn fmt_money_accessors(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        struct_name: &str,
+        fields: &[&Field],
+    ) -> fmt::Result {
+        let field_named = |name: &str| {
+            fields
+                .iter()
+                .find(|f| f.field.last().is_some_and(|n| n == name))
+        };
+        let currency_field = |name: &str| {
+            field_named(name).filter(|f| {
+                matches!(f.field_type, FieldType::Scalar(FieldTypeBase::String))
+                    && string_enum_type(f, self.endpoint_name) == Some("Currency")
+            })
+        };
+
+        let pairs = fields
+            .iter()
+            .filter(|f| matches!(f.field_type, FieldType::Scalar(FieldTypeBase::Number)))
+            .filter_map(|amount| {
+                let name = amount.field.last()?;
+                let currency = currency_field(&format!("{}_currency_code", name))
+                    .or_else(|| currency_field(&format!("{}_currency", name)))
+                    .or_else(|| {
+                        let (_, currency) = name.split_once("_in_")?;
+                        currency_field(currency)
+                    })
+                    .or_else(|| {
+                        (name == "amount"
+                            || name.ends_with("_amount")
+                            || name == "value"
+                            || name == "balance")
+                            .then(|| {
+                                currency_field("currency")
+                                    .or_else(|| currency_field("currency_code"))
+                            })
+                            .flatten()
+                    })?;
+
+                Some((*amount, currency))
+            })
+            .collect::<Vec<_>>();
+
+        if pairs.is_empty() {
+            return Ok(());
+        }
+
+        writeln!(f, "impl {} {{", struct_name)?;
+        for (i, (amount, currency)) in pairs.iter().enumerate() {
+            let amount_name = amount.field.last().unwrap();
+            let currency_name = currency.field.last().unwrap();
+            let value = |field: &Field, name: &str| {
+                if field.optional {
+                    format!("self.{}.as_ref()?.to_owned()", name)
+                } else {
+                    format!("self.{}.to_owned()", name)
+                }
+            };
+            let money = format!(
+                "Money::new({}, {})",
+                value(amount, amount_name),
+                value(currency, currency_name)
+            );
+
+            if i > 0 {
+                writeln!(f)?;
+            }
+            writeln!(f, "    /// `{}` in `{}`.", amount_name, currency_name)?;
+            if amount.optional || currency.optional {
+                writeln!(
+                    f,
+                    "    pub fn {}_money(&self) -> Option<Money> {{",
+                    amount_name
+                )?;
+                writeln!(f, "        Some({})", money)?;
+            } else {
+                writeln!(f, "    pub fn {}_money(&self) -> Money {{", amount_name)?;
+                writeln!(f, "        {}", money)?;
+            }
+            writeln!(f, "    }}")?;
+        }
+        writeln!(f, "}}")?;
+        writeln!(f)
+    }
+
+    /// Write a constructor taking the required fields, and a setter for each
+    /// optional field, so that callers don't need to list every optional field.
+    fn fmt_builder(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        struct_name: &str,
+        fields: &[&Field],
+    ) -> fmt::Result {
+        let fields = fields
+            .iter()
+            .filter_map(|field| match field.field[..] {
+                [ref prefix_segments @ .., ref field_name]
+                    if field_name != "supported_denominations" =>
+                {
+                    Some((
+                        *field,
+                        field_rust_name(field_name),
+                        self.field_rust_type(field, prefix_segments, field_name),
+                    ))
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let required = fields
+            .iter()
+            .filter(|(field, _, _)| !field.optional)
+            .collect::<Vec<_>>();
+
+        writeln!(f, "impl {} {{", struct_name)?;
+        if required.len() > 7 {
+            writeln!(f, "    #[allow(clippy::too_many_arguments)]")?;
+        }
+        write!(f, "    pub fn new(")?;
+        for (i, (_, name, rust_type)) in required.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}: {}", name, rust_type)?;
+        }
+        writeln!(f, ") -> Self {{")?;
+        writeln!(f, "        Self {{")?;
+        for (field, name, _) in fields.iter() {
+            if field.optional {
+                writeln!(f, "            {}: None,", name)?;
+            } else {
+                writeln!(f, "            {},", name)?;
+            }
+        }
+        writeln!(f, "        }}")?;
+        writeln!(f, "    }}")?;
+
+        for (field, name, rust_type) in fields.iter().filter(|(field, _, _)| field.optional) {
+            let inner_type = rust_type
+                .strip_prefix("Option<")
+                .and_then(|t| t.strip_suffix('>'))
+                .unwrap_or(rust_type);
+
+            writeln!(f)?;
+            write!(f, "{}", DocComment(&field.description))?;
+            writeln!(
+                f,
+                "    pub fn {}(mut self, {}: {}) -> Self {{",
+                name, name, inner_type
+            )?;
+            writeln!(f, "        self.{} = Some({});", name, name)?;
+            writeln!(f, "        self")?;
+            writeln!(f, "    }}")?;
+        }
+        writeln!(f, "}}")?;
+        writeln!(f)?;
+
+        Ok(())
+    }
+
+    fn field_rust_type(
+        &self,
+        field: &Field,
+        prefix_segments: &[String],
+        field_name: &str,
+    ) -> String {
+        let ApiStruct {
+            tag, endpoint_name, ..
+        } = *self;
+
+        let rust_type = if field.field_type.is_hash() {
+            let path = [prefix_segments, &[field_name.to_string()]].concat();
+            let shared_type = self
+                .shared_types
+                .and_then(|s| s.get(self.version, endpoint_name, &path));
+
+            let rust_type = if field_name == "cash_account_transaction_type" {
+                "CashAccountTransactionType".to_string()
+            } else if let Some(shared_type) = shared_type {
+                shared_type.to_string()
+            } else {
+                format!(
+                    "{}{}{}{}",
+                    endpoint_name,
+                    prefix_segments
+                        .iter()
+                        .map(|s| s.to_upper_camel_case())
+                        .collect::<String>(),
+                    field_name.to_upper_camel_case(),
+                    tag
+                )
+            };
+
+            if field.field_type.is_array() {
+                format!("Vec<{}>", rust_type)
+            } else {
+                rust_type
+            }
+        } else if let Some(rust_type) = field
+            .field_type
+            .is_string()
+            .then(|| string_enum_type(field, endpoint_name))
+            .flatten()
+        {
+            rust_type.to_string()
+        } else {
+            FieldTypeRustTypeNameDisplay(&field.field_type).to_string()
+        };
+
+        if field.optional {
+            format!("Option<{}>", rust_type)
+        } else {
+            rust_type
+        }
+    }
 }
 
-fn string_enum_type(s: &str, endpoint_name: &str) -> Option<&'static str> {
-    match s {
-        s if s.ends_with("currency_code") || s.ends_with("currency") => Some("Currency"),
+fn field_rust_name(field_name: &str) -> &str {
+    match field_name {
+        "self" => "itself",
+        "type" => "r#type",
+        field_name => field_name,
+    }
+}
+
+/// The code enum for a string field, inferred from its name. Currency fields
+/// documented as holding a symbol such as `AU$` rather than a code stay strings.
+pub fn string_enum_type(field: &Field, endpoint_name: &str) -> Option<&'static str> {
+    let holds_symbol = field.description.contains("currency symbol");
+
+    match field.field.last()?.as_str() {
+        s if s.ends_with("currency_code") || s.ends_with("currency") => {
+            (!holds_symbol).then_some("Currency")
+        }
         "country_code" => Some("Country"),
         "market" | "market_code" => Some("Market"),
         "transaction_type" => Some("TradeDescription"),
@@ -351,6 +1068,10 @@ impl<'a> fmt::Display for FieldTypeBaseRustTypeNameDisplay<'a> {
             FieldTypeBase::Number => write!(f, "Number"),
             FieldTypeBase::DateTime => write!(f, "DateTime<FixedOffset>"),
             FieldTypeBase::Unit => write!(f, "()"),
+            FieldTypeBase::Id(id_type) => write!(f, "{}", id_type),
+            FieldTypeBase::Enum(enum_name, _) => write!(f, "{}", enum_name),
+            FieldTypeBase::Link => write!(f, "Link"),
+            FieldTypeBase::Grouping => write!(f, "Grouping"),
             FieldTypeBase::File => write!(f, "()"),
             FieldTypeBase::Boolean => write!(f, "bool"),
             FieldTypeBase::Group => unreachable!(),
@@ -383,7 +1104,28 @@ impl<'a> fmt::Display for FieldTypeBaseRustTypeNameDisplay<'a> {
     }
 }
 
-struct DocComment<'a>(&'a str);
+/// A struct shared by several endpoints, see [`SharedTypes`].
+pub struct SharedTypeStruct<'a>(pub &'a str, pub &'a SharedType, pub &'a SharedTypes);
+
+impl<'a> fmt::Display for SharedTypeStruct<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self(type_name, shared_type, shared_types) = *self;
+        let fields = shared_type.fields.iter().collect::<Vec<_>>();
+
+        write!(
+            f,
+            "{}",
+            DocComment(&format!(
+                "The <code>{}</code> of several endpoints' responses.",
+                shared_type.field_name
+            ))
+        )?;
+        ApiStruct::success(type_name, "", &IndexMap::new(), shared_types)
+            .fmt_struct(f, type_name, &fields)
+    }
+}
+
+pub struct DocComment<'a>(pub &'a str);
 
 impl<'a> fmt::Display for DocComment<'a> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
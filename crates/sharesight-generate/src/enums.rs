@@ -0,0 +1,98 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+};
+
+use heck::ToUpperCamelCase;
+
+use crate::api_data::{ApiEndpoint, FieldType, FieldTypeBase};
+
+/// The enums detected from string fields' documented values, see
+/// `ApiEndpoint::fix_enums`. Fields sharing an enum name share one type with the
+/// values of all of them.
+#[derive(Debug, Default)]
+pub struct StringEnums {
+    enums: BTreeMap<String, (BTreeSet<String>, Vec<String>)>,
+}
+
+impl StringEnums {
+    pub fn collect<'a>(api_endpoints: impl IntoIterator<Item = &'a ApiEndpoint>) -> Self {
+        let mut string_enums = StringEnums::default();
+
+        for api_endpoint in api_endpoints {
+            for field in api_endpoint
+                .parameter
+                .fields
+                .parameter
+                .iter()
+                .chain(api_endpoint.success.api_fields())
+            {
+                if let FieldType::Scalar(FieldTypeBase::Enum(enum_name, values)) = &field.field_type
+                {
+                    let (field_names, all_values) =
+                        string_enums.enums.entry(enum_name.clone()).or_default();
+                    field_names.extend(field.field.last().cloned());
+                    for value in values {
+                        if !all_values
+                            .iter()
+                            .any(|v| v.to_upper_camel_case() == value.to_upper_camel_case())
+                        {
+                            all_values.push(value.clone());
+                        }
+                    }
+                }
+            }
+        }
+
+        string_enums
+    }
+
+    pub fn enums(&self) -> impl Iterator<Item = StringEnum<'_>> {
+        self.enums
+            .iter()
+            .map(|(name, (field_names, values))| StringEnum {
+                name,
+                field_names,
+                values,
+            })
+    }
+}
+
+pub struct StringEnum<'a> {
+    name: &'a str,
+    field_names: &'a BTreeSet<String>,
+    values: &'a [String],
+}
+
+impl<'a> fmt::Display for StringEnum<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            name,
+            field_names,
+            values,
+        } = *self;
+
+        writeln!(f, "string_enum! {{")?;
+        writeln!(
+            f,
+            "    /// The documented values of {} fields.",
+            field_names
+                .iter()
+                .map(|n| format!("`{}`", n))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )?;
+        writeln!(f, "    pub enum {} {{", name)?;
+        for value in values {
+            writeln!(
+                f,
+                "        {} = \"{}\",",
+                value.to_upper_camel_case(),
+                value
+            )?;
+        }
+        writeln!(f, "    }}")?;
+        writeln!(f, "}}")?;
+        writeln!(f)
+    }
+}
@@ -2,13 +2,17 @@ use std::{collections::BTreeMap, env, fs::File, io::Write, path::PathBuf, str::F
 
 mod api_data;
 mod display;
+mod enums;
+mod shared;
 
 use api_data::{ApiData, ApiEndpoint};
 use clap::Parser;
-use display::ApiEndpointStruct;
+use display::{ApiEndpointReExport, ApiEndpointStruct, EndpointRegistry, SharedTypeStruct};
+use enums::StringEnums;
 use indexmap::IndexMap;
 use log::info;
 use loose_semver::Version;
+use shared::SharedTypes;
 
 /// Generate sharesight types from the swagger manifest
 #[derive(Debug, Parser)]
@@ -36,7 +40,7 @@ fn main() -> anyhow::Result<()> {
         info!("Reading {:?}", input);
 
         let ApiData { api: endpoints } = serde_json::from_reader::<_, ApiData>(File::open(input)?)?;
-        api_endpoints.extend(endpoints.into_iter());
+        api_endpoints.extend(endpoints);
     }
 
     writeln!(f, "use crate::types_prelude::*;")?;
@@ -63,6 +67,14 @@ fn main() -> anyhow::Result<()> {
             continue;
         }
 
+        if opt
+            .only
+            .as_ref()
+            .is_some_and(|only| !only.iter().any(|name| name == &api_endpoint.name))
+        {
+            continue;
+        }
+
         let version = api_endpoint.version.parse::<Version>()?;
 
         let by_version = by_name_and_version
@@ -71,20 +83,68 @@ fn main() -> anyhow::Result<()> {
         by_version.insert(version, api_endpoint);
     }
 
-    for api_endpoint in by_name_and_version.into_values().filter_map(|mut v| {
-        v.remove(&Version::from_str("3.0.0").unwrap())
-            .or_else(|| v.remove(&Version::from_str("2.0.0").unwrap()))
-            .or_else(|| v.remove(&Version::from_str("2.1.0").unwrap()))
-            .or_else(|| v.into_values().next_back())
-    }) {
-        if let Some(ref only) = opt.only {
-            if !only.iter().any(|name| name == &api_endpoint.name) {
-                continue;
-            }
+    // The version of each endpoint that is re-exported at the top level.
+    let current_versions = by_name_and_version
+        .values()
+        .filter_map(|v| {
+            ["3.0.0", "2.0.0", "2.1.0"]
+                .into_iter()
+                .map(|version| Version::from_str(version).unwrap())
+                .find(|version| v.contains_key(version))
+                .or_else(|| v.keys().next_back().cloned())
+        })
+        .collect::<Vec<_>>();
+
+    let api_endpoints = by_name_and_version
+        .values()
+        .flat_map(BTreeMap::values)
+        .collect::<Vec<_>>();
+    let current_endpoints = by_name_and_version
+        .values()
+        .zip(&current_versions)
+        .map(|(v, version)| &v[version])
+        .collect::<Vec<_>>();
+
+    let shared_types = SharedTypes::detect(api_endpoints.iter().copied());
+
+    for (type_name, shared_type) in shared_types.types() {
+        write!(
+            f,
+            "{}",
+            SharedTypeStruct(type_name, shared_type, &shared_types)
+        )?;
+    }
+
+    for string_enum in StringEnums::collect(api_endpoints.iter().copied()).enums() {
+        write!(f, "{}", string_enum)?;
+    }
+
+    for (module, version) in [("v2", "2.0"), ("v2_1", "2.1"), ("v3", "3")] {
+        writeln!(f, "/// The endpoints of version {} of the API.", version)?;
+        writeln!(f, "pub mod {} {{", module)?;
+        writeln!(f, "    use super::*;")?;
+        writeln!(f)?;
+
+        let module_endpoints = api_endpoints
+            .iter()
+            .copied()
+            .filter(|api_endpoint| api_endpoint.module() == module)
+            .collect::<Vec<_>>();
+        for api_endpoint in &module_endpoints {
+            write!(f, "{}", ApiEndpointStruct(api_endpoint, &shared_types))?;
         }
 
-        write!(f, "{}", ApiEndpointStruct(&api_endpoint))?;
+        write!(f, "{}", EndpointRegistry(&module_endpoints, Some(version)))?;
+        writeln!(f, "}}")?;
+        writeln!(f)?;
+    }
+
+    for api_endpoint in &current_endpoints {
+        write!(f, "{}", ApiEndpointReExport(api_endpoint, &shared_types))?;
     }
+    writeln!(f)?;
+
+    write!(f, "{}", EndpointRegistry(&current_endpoints, None))?;
 
     Ok(())
 }
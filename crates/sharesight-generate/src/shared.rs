@@ -0,0 +1,268 @@
+use std::{collections::HashMap, fmt};
+
+use heck::ToUpperCamelCase;
+use indexmap::IndexMap;
+
+use log::warn;
+
+use crate::api_data::{group_fields_by_prefix, ApiEndpoint, Field};
+
+/// Success fields that mean the same thing in every endpoint, and the single type
+/// generated for them. `HasApiTransaction` and `HasLinks` in `types_prelude.rs`
+/// refer to these type names.
+pub const SHARED_TYPE_NAMES: &[(&str, &str)] =
+    &[("api_transaction", "ApiTransaction"), ("links", "Links")];
+
+/// Nested success structs that mean the same thing across endpoints, and the shared
+/// type emitted for each of them.
+#[derive(Debug, Default)]
+pub struct SharedTypes {
+    names: HashMap<(String, String, Vec<String>), String>,
+    types: IndexMap<String, SharedType>,
+}
+
+#[derive(Debug)]
+pub struct SharedType {
+    pub field_name: &'static str,
+    pub fields: Vec<Field>,
+}
+
+/// A struct in one endpoint's response that may use a shared type.
+struct SharedTypeUse<'a> {
+    version: String,
+    endpoint_name: String,
+    prefix: Vec<String>,
+    fields: Vec<&'a Field>,
+}
+
+impl SharedTypes {
+    /// Merges the structs for each of [`SHARED_TYPE_NAMES`] into one type with every
+    /// field any of them has. Fields that some endpoints don't have, or have as
+    /// optional, are optional in the shared type.
+    pub fn detect<'a>(api_endpoints: impl IntoIterator<Item = &'a ApiEndpoint>) -> Self {
+        let mut uses = IndexMap::<&'static str, Vec<_>>::new();
+
+        for api_endpoint in api_endpoints {
+            let endpoint_name = api_endpoint.name.to_upper_camel_case();
+
+            for (prefix, fields) in group_fields_by_prefix(api_endpoint.success.api_fields()) {
+                let Some(field_name) = prefix.last() else {
+                    continue;
+                };
+                let Some(&(field_name, _)) =
+                    SHARED_TYPE_NAMES.iter().find(|(n, _)| n == field_name)
+                else {
+                    continue;
+                };
+                if fields.iter().any(|f| f.field_type.is_hash()) {
+                    continue;
+                }
+
+                uses.entry(field_name).or_default().push(SharedTypeUse {
+                    version: api_endpoint.version.clone(),
+                    endpoint_name: endpoint_name.clone(),
+                    prefix: prefix.to_vec(),
+                    fields,
+                });
+            }
+        }
+
+        let mut shared_types = SharedTypes::default();
+
+        for (field_name, uses) in uses {
+            let (_, type_name) = SHARED_TYPE_NAMES
+                .iter()
+                .find(|(n, _)| *n == field_name)
+                .unwrap();
+            let uses = compatible_uses(field_name, uses);
+            if uses.len() < 2 {
+                continue;
+            }
+
+            shared_types.types.insert(
+                type_name.to_string(),
+                SharedType {
+                    field_name,
+                    fields: merge_fields(&uses),
+                },
+            );
+
+            for SharedTypeUse {
+                version,
+                endpoint_name,
+                prefix,
+                ..
+            } in uses
+            {
+                shared_types
+                    .names
+                    .insert((version, endpoint_name, prefix), type_name.to_string());
+            }
+        }
+
+        shared_types
+    }
+
+    /// The shared type used for the success struct at `path` within a version of an
+    /// endpoint, if any.
+    pub fn get(&self, version: &str, endpoint_name: &str, path: &[String]) -> Option<&str> {
+        self.names
+            .get(&(
+                version.to_string(),
+                endpoint_name.to_string(),
+                path.to_vec(),
+            ))
+            .map(String::as_str)
+    }
+
+    pub fn types(&self) -> impl Iterator<Item = (&str, &SharedType)> {
+        self.types.iter().map(|(k, v)| (k.as_str(), v))
+    }
+}
+
+/// The uses whose fields have the same types as the same named fields of the uses
+/// before them. The others keep their own types.
+fn compatible_uses<'a>(field_name: &str, uses: Vec<SharedTypeUse<'a>>) -> Vec<SharedTypeUse<'a>> {
+    let mut field_types = HashMap::<&str, String>::new();
+    let mut compatible = Vec::new();
+
+    for shared_type_use in uses {
+        let conflicts = shared_type_use.fields.iter().any(|f| {
+            field_types
+                .get(last_name(f))
+                .is_some_and(|t| *t != format!("{:?}", f.field_type))
+        });
+        if conflicts {
+            warn!(
+                "{} of {} doesn't match the other {} and isn't shared",
+                shared_type_use.prefix.join("."),
+                shared_type_use.endpoint_name,
+                field_name
+            );
+            continue;
+        }
+
+        for field in &shared_type_use.fields {
+            field_types.insert(last_name(field), format!("{:?}", field.field_type));
+        }
+        compatible.push(shared_type_use);
+    }
+
+    compatible
+}
+
+fn merge_fields(uses: &[SharedTypeUse]) -> Vec<Field> {
+    let mut fields = IndexMap::<&str, Field>::new();
+
+    for field in uses.iter().flat_map(|u| &u.fields) {
+        fields
+            .entry(last_name(field))
+            .or_insert_with(|| (*field).clone());
+    }
+
+    for (name, field) in fields.iter_mut() {
+        let uses_of_field = uses
+            .iter()
+            .map(|u| u.fields.iter().find(|f| last_name(f) == *name))
+            .collect::<Vec<_>>();
+
+        field.optional = uses_of_field.iter().any(|f| f.is_none_or(|f| f.optional));
+
+        // Docs are often written for one endpoint, so only keep those that every
+        // endpoint with the field agrees on.
+        if !uses_of_field
+            .iter()
+            .flatten()
+            .all(|f| f.description == field.description)
+        {
+            field.description = String::new();
+        }
+    }
+
+    fields.into_values().collect()
+}
+
+fn last_name(field: &Field) -> &str {
+    field.field.last().map(String::as_str).unwrap_or_default()
+}
+
+pub struct SharedTypeAlias<'a> {
+    pub alias: &'a str,
+    pub type_name: &'a str,
+}
+
+impl<'a> fmt::Display for SharedTypeAlias<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { alias, type_name } = *self;
+
+        writeln!(f, "#[deprecated(note = \"use `{}` instead\")]", type_name)?;
+        writeln!(f, "pub type {} = {};", alias, type_name)?;
+        writeln!(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api_data::tests::{endpoint, field};
+
+    fn shared_endpoint(name: &str, api_transaction: &[&str], links: &[&str]) -> ApiEndpoint {
+        let mut success = vec![
+            field("api_transaction", "Hash", "Api transaction"),
+            field("links", "Hash", "Links"),
+        ];
+        success.extend(
+            api_transaction
+                .iter()
+                .map(|name| field(&format!("api_transaction.{name}"), "Integer", "Integer")),
+        );
+        success.extend(
+            links
+                .iter()
+                .map(|link| field(&format!("links.{link}"), "String", "Url")),
+        );
+
+        let mut endpoint = endpoint(vec![], success);
+        endpoint.name = name.to_string();
+        endpoint
+    }
+
+    fn shared_fields<'a>(shared_types: &'a SharedTypes, name: &str) -> Vec<(&'a str, bool)> {
+        let (_, shared_type) = shared_types.types().find(|(n, _)| *n == name).unwrap();
+
+        shared_type
+            .fields
+            .iter()
+            .map(|f| (last_name(f), f.optional))
+            .collect()
+    }
+
+    #[test]
+    fn endpoints_share_one_type() {
+        let endpoints = [
+            shared_endpoint("ThingsList", &["id"], &["self"]),
+            shared_endpoint("ThingShow", &["id", "version"], &["self", "portfolio"]),
+        ];
+        let shared_types = SharedTypes::detect(&endpoints);
+
+        for name in ["ThingsList", "ThingShow"] {
+            assert_eq!(
+                Some("Links"),
+                shared_types.get("3.0.0", name, &["links".to_string()])
+            );
+            assert_eq!(
+                Some("ApiTransaction"),
+                shared_types.get("3.0.0", name, &["api_transaction".to_string()])
+            );
+        }
+
+        assert_eq!(
+            vec![("id", false), ("version", true)],
+            shared_fields(&shared_types, "ApiTransaction")
+        );
+        assert_eq!(
+            vec![("self", true), ("portfolio", true)],
+            shared_fields(&shared_types, "Links")
+        );
+    }
+}
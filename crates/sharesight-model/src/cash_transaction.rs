@@ -0,0 +1,80 @@
+use chrono::{DateTime, FixedOffset};
+use sharesight_types::{
+    CashAccountId, CashAccountTransactionCreateCashAccountTransactionSuccess,
+    CashAccountTransactionId, CashAccountTransactionTypeName,
+    CashAccountTransactionUpdateCashAccountTransactionSuccess,
+    CashAccountTransactionsListCashAccountTransactionsSuccess, HoldingId, Number, PayoutId,
+    TradeId,
+};
+
+/// A deposit, withdrawal or other movement of money in a cash account.
+#[derive(Debug, Clone)]
+pub struct CashTransaction {
+    pub id: CashAccountTransactionId,
+    pub cash_account_id: CashAccountId,
+    pub date_time: DateTime<FixedOffset>,
+    pub amount: Number,
+    /// The cash account's balance after this transaction.
+    pub balance: Number,
+    pub transaction_type: CashAccountTransactionTypeName,
+    /// Only returned when creating or updating a transaction.
+    pub description: Option<String>,
+    pub foreign_identifier: Option<String>,
+    pub holding_id: Option<HoldingId>,
+    pub trade_id: Option<TradeId>,
+    pub payout_id: Option<PayoutId>,
+}
+
+impl From<CashAccountTransactionsListCashAccountTransactionsSuccess> for CashTransaction {
+    fn from(transaction: CashAccountTransactionsListCashAccountTransactionsSuccess) -> Self {
+        Self {
+            id: transaction.id,
+            cash_account_id: transaction.cash_account_id,
+            date_time: transaction.date_time,
+            amount: transaction.amount,
+            balance: transaction.balance,
+            transaction_type: transaction.cash_account_transaction_type.name,
+            description: None,
+            foreign_identifier: transaction.foreign_identifier,
+            holding_id: transaction.holding_id,
+            trade_id: transaction.trade_id,
+            payout_id: transaction.payout_id,
+        }
+    }
+}
+
+impl From<CashAccountTransactionCreateCashAccountTransactionSuccess> for CashTransaction {
+    fn from(transaction: CashAccountTransactionCreateCashAccountTransactionSuccess) -> Self {
+        Self {
+            id: transaction.id,
+            cash_account_id: transaction.cash_account_id,
+            date_time: transaction.date_time,
+            amount: transaction.amount,
+            balance: transaction.balance,
+            transaction_type: transaction.cash_account_transaction_type.name,
+            description: Some(transaction.description),
+            foreign_identifier: transaction.foreign_identifier,
+            holding_id: transaction.holding_id,
+            trade_id: transaction.trade_id,
+            payout_id: None,
+        }
+    }
+}
+
+impl From<CashAccountTransactionUpdateCashAccountTransactionSuccess> for CashTransaction {
+    fn from(transaction: CashAccountTransactionUpdateCashAccountTransactionSuccess) -> Self {
+        Self {
+            id: transaction.id,
+            cash_account_id: transaction.cash_account_id,
+            date_time: transaction.date_time,
+            amount: transaction.amount,
+            balance: transaction.balance,
+            transaction_type: transaction.cash_account_transaction_type.name,
+            description: Some(transaction.description),
+            foreign_identifier: transaction.foreign_identifier,
+            holding_id: transaction.holding_id,
+            trade_id: transaction.trade_id,
+            payout_id: None,
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use chrono::NaiveDate;
+use sharesight_types::{
+    Currency, HoldingId, HoldingListHoldingsInstrumentSuccess, HoldingListHoldingsSuccess,
+    HoldingPortfolioListHoldingsInstrumentSuccess, HoldingPortfolioListHoldingsSuccess,
+    HoldingShowHoldingInstrumentSuccess, HoldingShowHoldingSuccess, InstrumentId, Market,
+    PerformanceShowReportHoldingsInstrumentSuccess, PerformanceShowReportHoldingsSuccess,
+    PortfolioId,
+};
+
+/// A position in a single instrument within a portfolio.
+#[derive(Debug, Clone)]
+pub struct Holding {
+    pub id: HoldingId,
+    pub portfolio_id: PortfolioId,
+    pub symbol: Option<String>,
+    pub valid_position: bool,
+    pub instrument: Instrument,
+    /// Only returned when showing a single holding.
+    pub inception_date: Option<NaiveDate>,
+}
+
+/// A security, currency or other asset that can be held.
+#[derive(Debug, Clone)]
+pub struct Instrument {
+    pub id: InstrumentId,
+    pub code: String,
+    pub name: String,
+    pub market: Market,
+    pub currency: Currency,
+    pub country_id: i64,
+    pub crypto: bool,
+    pub expired: Option<bool>,
+    pub expires_on: Option<NaiveDate>,
+    pub tz_name: String,
+    pub industry_classification_name: String,
+    pub sector_classification_name: String,
+}
+
+macro_rules! impl_from_instrument {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl From<$ty> for Instrument {
+                fn from(instrument: $ty) -> Self {
+                    Self {
+                        id: instrument.id,
+                        code: instrument.code,
+                        name: instrument.name,
+                        market: instrument.market_code,
+                        currency: instrument.currency_code,
+                        country_id: instrument.country_id,
+                        crypto: instrument.crypto,
+                        expired: Some(instrument.expired),
+                        expires_on: Some(instrument.expires_on),
+                        tz_name: instrument.tz_name,
+                        industry_classification_name: instrument.industry_classification_name,
+                        sector_classification_name: instrument.sector_classification_name,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_instrument!(
+    HoldingListHoldingsInstrumentSuccess,
+    HoldingShowHoldingInstrumentSuccess,
+    HoldingPortfolioListHoldingsInstrumentSuccess,
+);
+
+impl From<PerformanceShowReportHoldingsInstrumentSuccess> for Instrument {
+    fn from(instrument: PerformanceShowReportHoldingsInstrumentSuccess) -> Self {
+        Self {
+            id: instrument.id,
+            code: instrument.code,
+            name: instrument.name,
+            market: instrument.market_code,
+            currency: instrument.currency_code,
+            country_id: instrument.country_id,
+            crypto: instrument.crypto,
+            expired: instrument.expired,
+            expires_on: instrument.expires_on,
+            tz_name: instrument.tz_name,
+            industry_classification_name: instrument.industry_classification_name,
+            sector_classification_name: instrument.sector_classification_name,
+        }
+    }
+}
+
+impl From<HoldingListHoldingsSuccess> for Holding {
+    fn from(holding: HoldingListHoldingsSuccess) -> Self {
+        Self {
+            id: holding.id,
+            portfolio_id: holding.portfolio.id,
+            symbol: Some(holding.symbol),
+            valid_position: holding.valid_position,
+            instrument: holding.instrument.into(),
+            inception_date: None,
+        }
+    }
+}
+
+impl From<HoldingShowHoldingSuccess> for Holding {
+    fn from(holding: HoldingShowHoldingSuccess) -> Self {
+        Self {
+            id: holding.id,
+            portfolio_id: holding.portfolio.id,
+            symbol: Some(holding.symbol),
+            valid_position: holding.valid_position,
+            instrument: holding.instrument.into(),
+            inception_date: Some(holding.inception_date),
+        }
+    }
+}
+
+impl From<HoldingPortfolioListHoldingsSuccess> for Holding {
+    fn from(holding: HoldingPortfolioListHoldingsSuccess) -> Self {
+        Self {
+            id: holding.id,
+            portfolio_id: holding.portfolio.id,
+            symbol: Some(holding.symbol),
+            valid_position: holding.valid_position,
+            instrument: holding.instrument.into(),
+            inception_date: None,
+        }
+    }
+}
+
+impl From<PerformanceShowReportHoldingsSuccess> for Holding {
+    fn from(holding: PerformanceShowReportHoldingsSuccess) -> Self {
+        Self {
+            id: holding.id,
+            portfolio_id: holding.portfolio.id,
+            symbol: None,
+            valid_position: holding.valid_position,
+            instrument: holding.instrument.into(),
+            inception_date: None,
+        }
+    }
+}
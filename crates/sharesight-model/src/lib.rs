@@ -0,0 +1,20 @@
+//! Canonical domain types for the Sharesight API.
+//!
+//! `sharesight-types` generates a separate struct for every endpoint, so the same
+//! concept (a trade, a payout, …) appears as many unrelated types. This crate
+//! provides a single type for each concept along with `From` conversions from
+//! every generated variant, so business logic only needs to be written once.
+//!
+//! Fields that are missing from some variants are `Option`s here.
+
+mod cash_transaction;
+mod holding;
+mod payout;
+mod portfolio;
+mod trade;
+
+pub use cash_transaction::CashTransaction;
+pub use holding::{Holding, Instrument};
+pub use payout::Payout;
+pub use portfolio::Portfolio;
+pub use trade::Trade;
@@ -0,0 +1,214 @@
+use chrono::NaiveDate;
+use sharesight_types::{
+    Currency, HoldingId, InstrumentId, ListHoldingPayoutsPayoutsSuccess,
+    ListPortfolioPayoutsPayoutsSuccess, Market, Number, PayoutCreatePayoutSuccess,
+    PayoutDescription, PayoutId, PayoutShowSuccess, PayoutState, PortfolioId,
+};
+
+/// A dividend, distribution or interest payment on a holding.
+#[derive(Debug, Clone)]
+pub struct Payout {
+    /// The payout ID. Unset for payouts that have not been saved yet.
+    pub id: Option<PayoutId>,
+    /// Not returned when listing the payouts of a single holding.
+    pub portfolio_id: Option<PortfolioId>,
+    /// Not returned when listing the payouts of a single holding.
+    pub holding_id: Option<HoldingId>,
+    /// Not returned when listing the payouts of a single holding.
+    pub instrument_id: Option<InstrumentId>,
+    pub symbol: String,
+    pub market: Market,
+    pub transaction_description: Option<PayoutDescription>,
+    pub paid_on: NaiveDate,
+    pub ex_date: Option<NaiveDate>,
+    pub amount: Number,
+    pub gross_amount: Number,
+    pub currency: Currency,
+    pub exchange_rate: Number,
+    pub resident_withholding_tax: Option<Number>,
+    pub non_resident_withholding_tax: Option<Number>,
+    pub tax_credit: Option<Number>,
+    pub non_taxable: bool,
+    pub franked_amount: Option<Number>,
+    pub unfranked_amount: Option<Number>,
+    pub trust: Option<bool>,
+    pub other_net_fsi: Option<Number>,
+    pub lic_capital_gain: Option<Number>,
+    pub extra_interest_payment_amount: Option<Number>,
+    pub capital_gains: Option<Number>,
+    pub discounted_capital_gains: Option<Number>,
+    pub interest_payment: Option<Number>,
+    pub foreign_source_income: Option<Number>,
+    pub deferred_income: Option<Number>,
+    pub non_assessable: Option<Number>,
+    pub amit_decrease_amount: Option<Number>,
+    pub amit_increase_amount: Option<Number>,
+    pub company_event_id: Option<i64>,
+    pub comments: String,
+    pub state: Option<PayoutState>,
+}
+
+impl From<ListPortfolioPayoutsPayoutsSuccess> for Payout {
+    fn from(payout: ListPortfolioPayoutsPayoutsSuccess) -> Self {
+        Self {
+            id: payout.id,
+            portfolio_id: Some(payout.portfolio_id),
+            holding_id: Some(payout.holding_id),
+            instrument_id: Some(payout.instrument_id),
+            symbol: payout.symbol,
+            market: payout.market,
+            transaction_description: None,
+            paid_on: payout.paid_on,
+            ex_date: payout.ex_date,
+            amount: payout.amount,
+            gross_amount: payout.gross_amount,
+            currency: payout.currency,
+            exchange_rate: payout.exchange_rate,
+            resident_withholding_tax: payout.resident_withholding_tax,
+            non_resident_withholding_tax: payout.non_resident_withholding_tax,
+            tax_credit: payout.tax_credit,
+            non_taxable: payout.non_taxable,
+            franked_amount: payout.franked_amount,
+            unfranked_amount: payout.unfranked_amount,
+            trust: payout.trust,
+            other_net_fsi: payout.other_net_fsi,
+            lic_capital_gain: None,
+            extra_interest_payment_amount: payout.extra_interest_payment_amount,
+            capital_gains: payout.capital_gains,
+            discounted_capital_gains: payout.discounted_capital_gains,
+            interest_payment: payout.interest_payment,
+            foreign_source_income: payout.foreign_source_income,
+            deferred_income: payout.deferred_income,
+            non_assessable: payout.non_assessable,
+            amit_decrease_amount: payout.amit_decrease_amount,
+            amit_increase_amount: payout.amit_increase_amount,
+            company_event_id: payout.company_event_id,
+            comments: payout.comments,
+            state: payout.state,
+        }
+    }
+}
+
+impl From<ListHoldingPayoutsPayoutsSuccess> for Payout {
+    fn from(payout: ListHoldingPayoutsPayoutsSuccess) -> Self {
+        Self {
+            id: payout.id,
+            portfolio_id: None,
+            holding_id: None,
+            instrument_id: None,
+            symbol: payout.symbol,
+            market: payout.market,
+            transaction_description: Some(payout.transaction_description),
+            paid_on: payout.paid_on,
+            ex_date: payout.ex_date,
+            amount: payout.amount,
+            gross_amount: payout.gross_amount,
+            currency: payout.currency,
+            exchange_rate: payout.exchange_rate,
+            resident_withholding_tax: payout.resident_withholding_tax,
+            non_resident_withholding_tax: payout.non_resident_withholding_tax,
+            tax_credit: payout.tax_credit,
+            non_taxable: payout.non_taxable,
+            franked_amount: payout.franked_amount,
+            unfranked_amount: payout.unfranked_amount,
+            trust: payout.trust,
+            other_net_fsi: payout.other_net_fsi,
+            lic_capital_gain: Some(payout.lic_capital_gain),
+            extra_interest_payment_amount: payout.extra_interest_payment_amount,
+            capital_gains: payout.capital_gains,
+            discounted_capital_gains: payout.discounted_capital_gains,
+            interest_payment: payout.interest_payment,
+            foreign_source_income: payout.foreign_source_income,
+            deferred_income: payout.deferred_income,
+            non_assessable: payout.non_assessable,
+            amit_decrease_amount: payout.amit_decrease_amount,
+            amit_increase_amount: payout.amit_increase_amount,
+            company_event_id: payout.company_event_id,
+            comments: payout.comments,
+            state: payout.state,
+        }
+    }
+}
+
+impl From<PayoutShowSuccess> for Payout {
+    fn from(payout: PayoutShowSuccess) -> Self {
+        Self {
+            id: Some(payout.id),
+            portfolio_id: Some(payout.portfolio_id),
+            holding_id: Some(payout.holding_id),
+            instrument_id: Some(payout.instrument_id),
+            symbol: payout.symbol,
+            market: payout.market,
+            transaction_description: None,
+            paid_on: payout.paid_on,
+            ex_date: payout.ex_date,
+            amount: payout.amount,
+            gross_amount: payout.gross_amount,
+            currency: payout.currency,
+            exchange_rate: payout.exchange_rate,
+            resident_withholding_tax: payout.resident_withholding_tax,
+            non_resident_withholding_tax: payout.non_resident_withholding_tax,
+            tax_credit: payout.tax_credit,
+            non_taxable: payout.non_taxable,
+            franked_amount: payout.franked_amount,
+            unfranked_amount: payout.unfranked_amount,
+            trust: payout.trust,
+            other_net_fsi: payout.other_net_fsi,
+            lic_capital_gain: Some(payout.lic_capital_gain),
+            extra_interest_payment_amount: payout.extra_interest_payment_amount,
+            capital_gains: payout.capital_gains,
+            discounted_capital_gains: payout.discounted_capital_gains,
+            interest_payment: None,
+            foreign_source_income: payout.foreign_source_income,
+            deferred_income: payout.deferred_income,
+            non_assessable: payout.non_assessable,
+            amit_decrease_amount: payout.amit_decrease_amount,
+            amit_increase_amount: payout.amit_increase_amount,
+            company_event_id: payout.company_event_id,
+            comments: payout.comments,
+            state: payout.state,
+        }
+    }
+}
+
+impl From<PayoutCreatePayoutSuccess> for Payout {
+    fn from(payout: PayoutCreatePayoutSuccess) -> Self {
+        Self {
+            id: payout.id,
+            portfolio_id: Some(payout.portfolio_id),
+            holding_id: Some(payout.holding_id),
+            instrument_id: Some(payout.instrument_id),
+            symbol: payout.symbol,
+            market: payout.market,
+            transaction_description: None,
+            paid_on: payout.paid_on,
+            ex_date: payout.ex_date,
+            amount: payout.amount,
+            gross_amount: payout.gross_amount,
+            currency: payout.currency,
+            exchange_rate: payout.exchange_rate,
+            resident_withholding_tax: payout.resident_withholding_tax,
+            non_resident_withholding_tax: payout.non_resident_withholding_tax,
+            tax_credit: payout.tax_credit,
+            non_taxable: payout.non_taxable,
+            franked_amount: payout.franked_amount,
+            unfranked_amount: payout.unfranked_amount,
+            trust: payout.trust,
+            other_net_fsi: payout.other_net_fsi,
+            lic_capital_gain: None,
+            extra_interest_payment_amount: payout.extra_interest_payment_amount,
+            capital_gains: payout.capital_gains,
+            discounted_capital_gains: payout.discounted_capital_gains,
+            interest_payment: payout.interest_payment,
+            foreign_source_income: payout.foreign_source_income,
+            deferred_income: payout.deferred_income,
+            // The create response documents this as a flag rather than an amount.
+            non_assessable: None,
+            amit_decrease_amount: payout.amit_decrease_amount,
+            amit_increase_amount: payout.amit_increase_amount,
+            company_event_id: payout.company_event_id,
+            comments: payout.comments,
+            state: payout.state,
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use chrono::NaiveDate;
+use sharesight_types::{
+    CashAccountId, Country, Currency, Number, PortfolioAccessLevel, PortfolioCreateSuccess,
+    PortfolioId, PortfolioListPortfoliosSuccess, PortfolioPortfolioSuccess, PortfolioShowSuccess,
+    PortfolioUpdateSuccess, SaleAllocationMethod,
+};
+
+/// A portfolio of holdings owned by a user.
+#[derive(Debug, Clone)]
+pub struct Portfolio {
+    pub id: PortfolioId,
+    pub name: String,
+    pub consolidated: Option<bool>,
+    pub external_identifier: String,
+    pub tz_name: String,
+    pub default_sale_allocation_method: SaleAllocationMethod,
+    pub cg_discount: Option<String>,
+    pub financial_year_end: String,
+    pub interest_method: String,
+    pub country_code: Country,
+    pub currency_code: Currency,
+    pub inception_date: NaiveDate,
+    /// Only returned when listing portfolios.
+    pub access_level: Option<PortfolioAccessLevel>,
+    pub user_id: Option<i64>,
+    /// Only returned when listing portfolios.
+    pub owner_name: Option<String>,
+    pub rwtr_rate: Option<Number>,
+    pub trader: Option<bool>,
+    pub disable_automatic_transactions: Option<bool>,
+    pub tax_entity_type: String,
+    pub trade_sync_cash_account_id: Option<CashAccountId>,
+    pub payout_sync_cash_account_id: Option<CashAccountId>,
+}
+
+impl From<PortfolioListPortfoliosSuccess> for Portfolio {
+    fn from(portfolio: PortfolioListPortfoliosSuccess) -> Self {
+        Self {
+            id: portfolio.id,
+            name: portfolio.name,
+            consolidated: portfolio.consolidated,
+            external_identifier: portfolio.external_identifier,
+            tz_name: portfolio.tz_name,
+            default_sale_allocation_method: portfolio.default_sale_allocation_method,
+            cg_discount: Some(portfolio.cg_discount),
+            financial_year_end: portfolio.financial_year_end,
+            interest_method: portfolio.interest_method,
+            country_code: portfolio.country_code,
+            currency_code: portfolio.currency_code,
+            inception_date: portfolio.inception_date,
+            access_level: portfolio.access_level,
+            user_id: portfolio.user_id,
+            owner_name: Some(portfolio.owner_name),
+            rwtr_rate: portfolio.rwtr_rate,
+            trader: portfolio.trader,
+            disable_automatic_transactions: portfolio.disable_automatic_transactions,
+            tax_entity_type: portfolio.tax_entity_type,
+            trade_sync_cash_account_id: portfolio.trade_sync_cash_account_id,
+            payout_sync_cash_account_id: portfolio.payout_sync_cash_account_id,
+        }
+    }
+}
+
+impl From<PortfolioPortfolioSuccess> for Portfolio {
+    fn from(portfolio: PortfolioPortfolioSuccess) -> Self {
+        Self {
+            id: portfolio.id,
+            name: portfolio.name,
+            consolidated: Some(portfolio.consolidated),
+            external_identifier: portfolio.external_identifier,
+            tz_name: portfolio.tz_name,
+            default_sale_allocation_method: portfolio.default_sale_allocation_method,
+            cg_discount: Some(portfolio.cg_discount),
+            financial_year_end: portfolio.financial_year_end,
+            interest_method: portfolio.interest_method,
+            country_code: portfolio.country_code,
+            currency_code: portfolio.currency_code,
+            inception_date: portfolio.inception_date,
+            access_level: portfolio.access_level,
+            user_id: Some(portfolio.user_id),
+            owner_name: Some(portfolio.owner_name),
+            rwtr_rate: Some(portfolio.rwtr_rate),
+            trader: Some(portfolio.trader),
+            disable_automatic_transactions: Some(portfolio.disable_automatic_transactions),
+            tax_entity_type: portfolio.tax_entity_type,
+            trade_sync_cash_account_id: Some(portfolio.trade_sync_cash_account_id),
+            payout_sync_cash_account_id: Some(portfolio.payout_sync_cash_account_id),
+        }
+    }
+}
+
+macro_rules! impl_from_portfolio_details {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl From<$ty> for Portfolio {
+                fn from(portfolio: $ty) -> Self {
+                    Self {
+                        id: portfolio.id,
+                        name: portfolio.name,
+                        consolidated: None,
+                        external_identifier: portfolio.external_identifier,
+                        tz_name: portfolio.tz_name,
+                        default_sale_allocation_method: portfolio.default_sale_allocation_method,
+                        cg_discount: portfolio.cg_discount.into(),
+                        financial_year_end: portfolio.financial_year_end,
+                        interest_method: portfolio.interest_method,
+                        country_code: portfolio.country_code,
+                        currency_code: portfolio.currency_code,
+                        inception_date: portfolio.inception_date,
+                        access_level: None,
+                        user_id: None,
+                        owner_name: None,
+                        rwtr_rate: Some(portfolio.rwtr_rate),
+                        trader: Some(portfolio.trader),
+                        disable_automatic_transactions: Some(portfolio.disable_automatic_transactions),
+                        tax_entity_type: portfolio.tax_entity_type,
+                        trade_sync_cash_account_id: Some(portfolio.trade_sync_cash_account_id),
+                        payout_sync_cash_account_id: Some(portfolio.payout_sync_cash_account_id),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_portfolio_details!(
+    PortfolioShowSuccess,
+    PortfolioCreateSuccess,
+    PortfolioUpdateSuccess,
+);
@@ -0,0 +1,180 @@
+use chrono::NaiveDate;
+use sharesight_types::{
+    Currency, HoldingId, HoldingTradesTradesSuccess, InstrumentId, Market, Number, PortfolioId,
+    TradeDescription, TradeId, TradeState, TradesCreateTradeSuccess, TradesShowSuccess,
+    TradesTradesSuccess,
+};
+
+/// A buy, sell or other transaction that changes a holding's quantity.
+#[derive(Debug, Clone)]
+pub struct Trade {
+    /// The trade ID. Unset for trades that have not been saved yet.
+    pub id: Option<TradeId>,
+    /// A unique identifier associated with this trade.
+    pub unique_identifier: Option<String>,
+    pub portfolio_id: PortfolioId,
+    pub holding_id: HoldingId,
+    pub instrument_id: InstrumentId,
+    pub symbol: String,
+    pub market: Market,
+    pub transaction_type: TradeDescription,
+    pub transaction_date: NaiveDate,
+    pub quantity: Number,
+    pub price: Number,
+    pub cost_base: Option<Number>,
+    pub exchange_rate: Number,
+    pub brokerage: Number,
+    pub brokerage_currency_code: Option<Currency>,
+    pub value: Number,
+    /// The date a capital return was paid on.
+    pub paid_on: Option<NaiveDate>,
+    pub company_event_id: Option<i64>,
+    pub comments: String,
+    pub state: Option<TradeState>,
+    pub confirmed: bool,
+    pub attachment_filename: Option<String>,
+    pub attachment_id: Option<i64>,
+}
+
+impl From<TradesTradesSuccess> for Trade {
+    fn from(trade: TradesTradesSuccess) -> Self {
+        Self {
+            id: trade.id,
+            unique_identifier: trade.unique_identifier,
+            portfolio_id: trade.portfolio_id,
+            holding_id: trade.holding_id,
+            instrument_id: trade.instrument_id,
+            symbol: trade.symbol,
+            market: trade.market,
+            transaction_type: trade.transaction_type,
+            transaction_date: trade.transaction_date,
+            quantity: trade.quantity,
+            price: trade.price,
+            cost_base: trade.cost_base,
+            exchange_rate: trade.exchange_rate,
+            brokerage: trade.brokerage,
+            brokerage_currency_code: trade.brokerage_currency_code,
+            value: trade.value,
+            paid_on: trade.paid_on,
+            company_event_id: trade.company_event_id,
+            comments: trade.comments,
+            state: trade.state,
+            confirmed: trade.confirmed,
+            attachment_filename: trade.attachment_filename,
+            attachment_id: trade.attachment_id,
+        }
+    }
+}
+
+impl From<HoldingTradesTradesSuccess> for Trade {
+    fn from(trade: HoldingTradesTradesSuccess) -> Self {
+        Self {
+            id: trade.id,
+            unique_identifier: trade.unique_identifier,
+            portfolio_id: trade.portfolio_id,
+            holding_id: trade.holding_id,
+            instrument_id: trade.instrument_id,
+            symbol: trade.symbol,
+            market: trade.market,
+            transaction_type: trade.transaction_type,
+            transaction_date: trade.transaction_date,
+            quantity: trade.quantity,
+            price: trade.price,
+            cost_base: trade.cost_base,
+            exchange_rate: trade.exchange_rate,
+            brokerage: trade.brokerage,
+            brokerage_currency_code: trade.brokerage_currency_code,
+            value: trade.value,
+            paid_on: trade.paid_on,
+            company_event_id: trade.company_event_id,
+            comments: trade.comments,
+            state: trade.state,
+            confirmed: trade.confirmed,
+            attachment_filename: trade.attachment_filename,
+            attachment_id: trade.attachment_id,
+        }
+    }
+}
+
+impl From<TradesShowSuccess> for Trade {
+    fn from(trade: TradesShowSuccess) -> Self {
+        Self {
+            id: trade.id,
+            unique_identifier: Some(trade.unique_identifier),
+            portfolio_id: trade.portfolio_id,
+            holding_id: trade.holding_id,
+            instrument_id: trade.instrument_id,
+            symbol: trade.symbol,
+            market: trade.market,
+            transaction_type: trade.transaction_type,
+            transaction_date: trade.transaction_date,
+            quantity: trade.quantity,
+            price: trade.price,
+            cost_base: trade.cost_base,
+            exchange_rate: trade.exchange_rate,
+            brokerage: trade.brokerage,
+            brokerage_currency_code: Some(trade.brokerage_currency_code),
+            value: trade.value,
+            paid_on: Some(trade.paid_on),
+            company_event_id: trade.company_event_id,
+            comments: trade.comments,
+            state: trade.state,
+            confirmed: trade.confirmed,
+            attachment_filename: trade.attachment_filename,
+            attachment_id: trade.attachment_id,
+        }
+    }
+}
+
+impl From<TradesCreateTradeSuccess> for Trade {
+    fn from(trade: TradesCreateTradeSuccess) -> Self {
+        Self {
+            id: trade.id,
+            unique_identifier: Some(trade.unique_identifier),
+            portfolio_id: trade.portfolio_id,
+            holding_id: trade.holding_id,
+            instrument_id: trade.instrument_id,
+            symbol: trade.symbol,
+            market: trade.market,
+            transaction_type: trade.transaction_type,
+            transaction_date: trade.transaction_date,
+            quantity: trade.quantity,
+            price: trade.price,
+            cost_base: Some(trade.cost_base),
+            exchange_rate: trade.exchange_rate,
+            brokerage: trade.brokerage,
+            brokerage_currency_code: Some(trade.brokerage_currency_code),
+            value: trade.value,
+            paid_on: Some(trade.paid_on),
+            company_event_id: trade.company_event_id,
+            comments: trade.comments,
+            state: trade.state,
+            confirmed: trade.confirmed,
+            attachment_filename: trade.attachment_filename,
+            attachment_id: trade.attachment_id,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_holding_trade_parses_string_id() {
+        let trade: HoldingTradesTradesSuccess = serde_json::from_str(include_str!(
+            "../../sharesight-types/test-data/holding_trade.json"
+        ))
+        .unwrap();
+
+        let trade = Trade::from(trade);
+
+        assert_eq!(trade.id, Some(TradeId(123)));
+        assert_eq!(trade.holding_id, HoldingId(2));
+        assert_eq!(trade.market, Market::ASX);
+        assert_eq!(
+            trade.transaction_date,
+            NaiveDate::from_ymd_opt(2024, 3, 1).unwrap()
+        );
+    }
+}
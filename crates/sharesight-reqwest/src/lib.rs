@@ -1,19 +1,23 @@
-use std::sync::Arc;
+use std::{borrow::Cow, fmt};
 
 use log::warn;
 use reqwest_middleware::reqwest;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use sharesight_types::{
-    ApiEndpoint, CashAccountsList, CashAccountsListCashAccountsSuccess, CashAccountsListParameters,
-    CashAccountsListSuccess, PortfolioList, PortfolioListParameters,
-    PortfolioListPortfoliosSuccess, PortfolioListSuccess,
+    ApiEndpoint, BaseUrl, CashAccountsList, CashAccountsListCashAccountsSuccess,
+    CashAccountsListParameters, CashAccountsListSuccess, Link, PortfolioList,
+    PortfolioListParameters, PortfolioListPortfoliosSuccess, PortfolioListSuccess,
 };
+use zeroize::Zeroizing;
 
 pub use aliri_tokens::TokenWithLifetime;
+pub use reqwest::{Certificate, Identity, Proxy};
+pub use sharesight_types::RedactionPolicy;
 
 pub struct Client {
     client: reqwest_middleware::ClientWithMiddleware,
-    host: Arc<str>,
+    base_url: BaseUrl,
+    redaction: RedactionPolicy,
 }
 
 impl Client {
@@ -21,58 +25,39 @@ impl Client {
         user_credentials_file: std::path::PathBuf,
         client_credentials_file: std::path::PathBuf,
     ) -> Result<Self, SharesightReqwestError> {
-        use predicates::prelude::PredicateBooleanExt;
-
-        let client = reqwest::Client::default();
-
-        let client_credentials = serde_json::from_reader::<_, ClientCredentials>(
-            std::fs::File::open(client_credentials_file)?,
-        )?;
-        let credentials =
-            std::sync::Arc::new(aliri_tokens::sources::oauth2::dto::ClientCredentials {
-                client_id: client_credentials.client_id,
-                client_secret: client_credentials.client_secret,
-            });
-
-        let credentials = aliri_tokens::sources::oauth2::dto::ClientCredentialsWithAudience {
-            credentials,
-            audience: "".into(),
-        };
-
-        let fallback = aliri_tokens::sources::oauth2::ClientCredentialsTokenSource::new(
-            client.clone(),
-            reqwest::Url::parse(&format!("https://{}/oauth2/token", client_credentials.host))
-                .unwrap(),
-            credentials,
-            aliri_tokens::TokenLifetimeConfig::default(),
-        );
-
-        let file_source = aliri_tokens::sources::file::FileTokenSource::new(user_credentials_file);
+        Self::builder(user_credentials_file, client_credentials_file)
+            .build()
+            .await
+    }
 
-        let token_source = aliri_tokens::sources::cache::CachedTokenSource::new(fallback)
-            .with_cache("file", file_source);
+    pub fn builder(
+        user_credentials_file: std::path::PathBuf,
+        client_credentials_file: std::path::PathBuf,
+    ) -> ClientBuilder {
+        ClientBuilder {
+            user_credentials_file,
+            client_credentials_file,
+            redaction: RedactionPolicy::default(),
+            proxies: Vec::new(),
+            root_certificates: Vec::new(),
+            built_in_root_certificates: true,
+            identity: None,
+        }
+    }
 
-        let token_watcher = aliri_tokens::TokenWatcher::spawn_from_token_source(
-            token_source,
-            aliri_tokens::jitter::RandomEarlyJitter::new(aliri_clock::DurationSecs(60)),
-            aliri_tokens::backoff::ErrorBackoffConfig::default(),
-        )
-        .await?;
-        let client = reqwest_middleware::ClientBuilder::new(client)
-            .with(
-                aliri_reqwest::AccessTokenMiddleware::new(token_watcher).with_predicate(
-                    aliri_reqwest::HttpsOnly
-                        .and(aliri_reqwest::ExactHostMatch::new(&client_credentials.host)),
-                ),
-            )
-            .build();
+    pub fn base_url(&self) -> &BaseUrl {
+        &self.base_url
+    }
 
-        Ok(Client {
-            host: client_credentials.host.into(),
-            client,
-        })
+    pub fn redaction(&self) -> RedactionPolicy {
+        self.redaction
     }
 
+    /// Send `parameters` to the endpoint `T`.
+    ///
+    /// Parameters are sent as a json body for every method, including GET, which
+    /// the API accepts. This differs from `sharesight-wasm`, which has to send GET
+    /// parameters as a query string because browsers won't send a GET body.
     pub async fn execute<'a, T: ApiEndpoint<'a>, U: DeserializeOwned>(
         &'a self,
         parameters: &'a T::Parameters,
@@ -86,13 +71,52 @@ impl Client {
         };
         let resp = self
             .client
-            .request(method, T::url(&self.host, parameters).to_string())
+            .request(method, T::url(&self.base_url, parameters).to_string())
             .json(parameters)
             .send()
-            .await?;
+            .await
+            .map_err(|e| e.redact(self.redaction))?;
+
+        self.decode(resp).await
+    }
+
+    /// Fetch a link from a response, such as `links.portfolio`, as the endpoint `T`
+    /// it points to, e.g. [`PortfolioShow`](sharesight_types::PortfolioShow).
+    ///
+    /// Links to anywhere but [`Client::base_url`] are rejected rather than sent with
+    /// the access token.
+    pub async fn follow<'a, T: ApiEndpoint<'a>>(
+        &'a self,
+        link: &Link,
+    ) -> Result<T::Success, SharesightReqwestError> {
+        if !BaseUrlMatch(self.base_url.clone()).matches(link.url()) {
+            return Err(SharesightReqwestError::ForeignLink(
+                self.redaction.url(link.url()),
+            ));
+        }
+        if !link.points_to::<T>() {
+            return Err(SharesightReqwestError::UnexpectedLink(
+                self.redaction.url(link.url()),
+                T::URL_PATH,
+            ));
+        }
+
+        let resp = self
+            .client
+            .get(link.url().clone())
+            .send()
+            .await
+            .map_err(|e| e.redact(self.redaction))?;
 
+        self.decode(resp).await
+    }
+
+    async fn decode<U: DeserializeOwned>(
+        &self,
+        resp: reqwest::Response,
+    ) -> Result<U, SharesightReqwestError> {
         if resp.status().is_success() {
-            let full = resp.bytes().await?;
+            let full = resp.bytes().await.map_err(|e| e.redact(self.redaction))?;
 
             let slice = if full.is_empty() {
                 b"null".as_slice()
@@ -101,18 +125,27 @@ impl Client {
             };
 
             Ok(serde_json::from_slice(slice).map_err(|e| {
+                let e = e.redact(self.redaction);
                 if let Ok(s) = std::str::from_utf8(&full) {
-                    warn!("Error deserializing json: {:?}\n{}", e, s);
+                    warn!(
+                        "Error deserializing json: {:?}\n{}",
+                        e,
+                        self.redaction.body(s)
+                    );
                 } else {
                     warn!("Error deserializing json - not valid utf-8: {:?}", e);
                 }
                 e
             })?)
         } else {
+            let url = self.redaction.url(resp.url());
+            let status = resp.status();
+            let body = resp.text().await.map_err(|e| e.redact(self.redaction))?;
+
             Err(SharesightReqwestError::Http(
-                resp.url().clone(),
-                resp.status(),
-                resp.text().await?,
+                url,
+                status,
+                self.redaction.body(&body),
             ))
         }
     }
@@ -121,18 +154,12 @@ impl Client {
         &self,
     ) -> Result<NameIndex<PortfolioListPortfoliosSuccess>, SharesightReqwestError> {
         let mut index = NameIndex::default();
-        let parameters = PortfolioListParameters {
-            consolidated: Some(true),
-            instrument_id: None,
-        };
+        let parameters = PortfolioListParameters::new().consolidated(true);
         let PortfolioListSuccess { portfolios, .. } =
             self.execute::<PortfolioList, _>(&parameters).await?;
         index.extend(portfolios);
 
-        let parameters = PortfolioListParameters {
-            consolidated: Some(false),
-            instrument_id: None,
-        };
+        let parameters = PortfolioListParameters::new().consolidated(false);
         let PortfolioListSuccess { portfolios, .. } =
             self.execute::<PortfolioList, _>(&parameters).await?;
         index.extend(portfolios);
@@ -146,7 +173,7 @@ impl Client {
     ) -> Result<NameIndex<CashAccountsListCashAccountsSuccess>, SharesightReqwestError> {
         let mut index = NameIndex::default();
 
-        let account_params = CashAccountsListParameters { date: None };
+        let account_params = CashAccountsListParameters::new();
         let CashAccountsListSuccess { cash_accounts, .. } =
             self.execute::<CashAccountsList, _>(&account_params).await?;
         let cash_accounts = cash_accounts
@@ -159,6 +186,293 @@ impl Client {
     }
 }
 
+impl fmt::Debug for Client {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Client")
+            .field("base_url", &self.base_url)
+            .field("redaction", &self.redaction)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Debug)]
+pub struct ClientBuilder {
+    user_credentials_file: std::path::PathBuf,
+    client_credentials_file: std::path::PathBuf,
+    redaction: RedactionPolicy,
+    proxies: Vec<Proxy>,
+    root_certificates: Vec<Certificate>,
+    built_in_root_certificates: bool,
+    identity: Option<Identity>,
+}
+
+impl ClientBuilder {
+    /// Set how much of the request URL and response body is kept in errors and logs.
+    pub fn redaction(mut self, redaction: RedactionPolicy) -> Self {
+        self.redaction = redaction;
+        self
+    }
+
+    /// Send requests, including OAuth token requests, through a proxy.
+    ///
+    /// Proxies configured with the `HTTP_PROXY`, `HTTPS_PROXY` and `NO_PROXY` environment
+    /// variables are used when no proxy is added.
+    pub fn proxy(mut self, proxy: Proxy) -> Self {
+        self.proxies.push(proxy);
+        self
+    }
+
+    /// Trust an additional root certificate, such as the one used by a TLS-intercepting proxy.
+    pub fn add_root_certificate(mut self, certificate: Certificate) -> Self {
+        self.root_certificates.push(certificate);
+        self
+    }
+
+    /// Whether to trust the built-in root certificates in addition to any added with
+    /// [`ClientBuilder::add_root_certificate`]. Defaults to `true`.
+    pub fn built_in_root_certificates(mut self, enabled: bool) -> Self {
+        self.built_in_root_certificates = enabled;
+        self
+    }
+
+    /// Present a client certificate when a server requests one.
+    pub fn identity(mut self, identity: Identity) -> Self {
+        self.identity = Some(identity);
+        self
+    }
+
+    /// Read the credentials files and fetch an access token.
+    ///
+    /// The client secret is kept in a [`Secret`] for as long as the client lives and
+    /// is zeroized when it's dropped. Access tokens aren't zeroized: the token response
+    /// body is buffered by `reqwest`, and the tokens are held and cached in the user
+    /// credentials file by `aliri_tokens`.
+    pub async fn build(self) -> Result<Client, SharesightReqwestError> {
+        let ClientBuilder {
+            user_credentials_file,
+            client_credentials_file,
+            redaction,
+            proxies,
+            root_certificates,
+            built_in_root_certificates,
+            identity,
+        } = self;
+
+        let mut client =
+            reqwest::Client::builder().tls_built_in_root_certs(built_in_root_certificates);
+        for proxy in proxies {
+            client = client.proxy(proxy);
+        }
+        for certificate in root_certificates {
+            client = client.add_root_certificate(certificate);
+        }
+        if let Some(identity) = identity {
+            client = client.identity(identity);
+        }
+        let client = client.build()?;
+
+        let client_credentials = serde_json::from_reader::<_, ClientCredentials>(
+            std::fs::File::open(client_credentials_file)?,
+        )
+        .map_err(|e| e.redact(redaction))?;
+        let base_url = client_credentials.base_url()?;
+        let fallback = ClientCredentialsTokenSource {
+            client: client.clone(),
+            token_url: reqwest::Url::parse(&base_url.oauth_token_url())?,
+            client_id: client_credentials.client_id,
+            client_secret: client_credentials.client_secret,
+            lifetime_config: aliri_tokens::TokenLifetimeConfig::default(),
+            redaction,
+        };
+
+        let file_source = aliri_tokens::sources::file::FileTokenSource::new(user_credentials_file);
+
+        let token_source = aliri_tokens::sources::cache::CachedTokenSource::new(fallback)
+            .with_cache("file", file_source);
+
+        let token_watcher = aliri_tokens::TokenWatcher::spawn_from_token_source(
+            token_source,
+            aliri_tokens::jitter::RandomEarlyJitter::new(aliri_clock::DurationSecs(60)),
+            aliri_tokens::backoff::ErrorBackoffConfig::default(),
+        )
+        .await?;
+        let client = reqwest_middleware::ClientBuilder::new(client)
+            .with(
+                aliri_reqwest::AccessTokenMiddleware::new(token_watcher)
+                    .with_predicate(BaseUrlMatch(base_url.clone())),
+            )
+            .build();
+
+        Ok(Client {
+            base_url,
+            client,
+            redaction,
+        })
+    }
+}
+
+/// Only attach an access token to requests sent to the API server itself.
+#[derive(Clone, Debug)]
+struct BaseUrlMatch(BaseUrl);
+
+impl BaseUrlMatch {
+    fn matches(&self, url: &reqwest::Url) -> bool {
+        let Self(base_url) = self;
+
+        url.scheme() == base_url.scheme().as_str()
+            && url
+                .host_str()
+                .is_some_and(|host| host.eq_ignore_ascii_case(base_url.host()))
+            && url.port_or_known_default() == Some(base_url.port_or_known_default())
+            && url
+                .path()
+                .strip_prefix(base_url.path_prefix())
+                .is_some_and(|path| path.is_empty() || path.starts_with('/'))
+    }
+}
+
+impl predicates::Predicate<reqwest::Request> for BaseUrlMatch {
+    fn eval(&self, req: &reqwest::Request) -> bool {
+        self.matches(req.url())
+    }
+}
+
+impl predicates::reflection::PredicateReflection for BaseUrlMatch {}
+
+impl fmt::Display for BaseUrlMatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "url starts with {}", self.0)
+    }
+}
+
+/// Requests tokens with the OAuth client credentials flow.
+///
+/// `aliri_tokens` has its own client credentials source, but it keeps the client
+/// secret in a `ClientSecret` that isn't zeroized. This source keeps it in the
+/// [`Secret`] read from the credentials file instead, so the only long lived copy of
+/// the client secret is zeroized when the [`Client`] is dropped.
+struct ClientCredentialsTokenSource {
+    client: reqwest::Client,
+    token_url: reqwest::Url,
+    client_id: aliri_tokens::ClientId,
+    client_secret: Secret,
+    lifetime_config: aliri_tokens::TokenLifetimeConfig,
+    redaction: RedactionPolicy,
+}
+
+impl ClientCredentialsTokenSource {
+    fn request(&self) -> ClientCredentialsRequest<'_> {
+        ClientCredentialsRequest {
+            grant_type: "client_credentials",
+            client_id: self.client_id.as_str(),
+            client_secret: self.client_secret.expose(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct ClientCredentialsRequest<'a> {
+    grant_type: &'static str,
+    client_id: &'a str,
+    client_secret: &'a str,
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: Secret,
+    expires_in: u64,
+}
+
+#[async_trait::async_trait]
+impl aliri_tokens::sources::AsyncTokenSource for ClientCredentialsTokenSource {
+    type Error = aliri_tokens::sources::oauth2::TokenRequestError;
+
+    async fn request_token(&mut self) -> Result<aliri_tokens::TokenWithLifetime, Self::Error> {
+        use aliri_tokens::sources::oauth2::TokenRequestError;
+
+        let resp = self
+            .client
+            .post(self.token_url.clone())
+            .json(&self.request())
+            .send()
+            .await
+            .map_err(|e| TokenRequestError::RequestSend(e.redact(self.redaction)))?;
+
+        if let Err(source) = resp.error_for_status_ref() {
+            let body = resp
+                .text()
+                .await
+                .map_err(|e| TokenRequestError::BodyReadError(e.redact(self.redaction)))?;
+            return Err(TokenRequestError::ErrorWithBody {
+                source: source.redact(self.redaction),
+                body: self.redaction.body(&body),
+            });
+        }
+
+        // The response buffer is owned and dropped by reqwest, so the copy of the
+        // access token in it isn't zeroized.
+        let body = resp
+            .bytes()
+            .await
+            .map_err(|e| TokenRequestError::BodyReadError(e.redact(self.redaction)))?;
+        let TokenResponse {
+            access_token,
+            expires_in,
+        } = serde_json::from_slice(&body).map_err(|e| e.redact(self.redaction))?;
+
+        Ok(self.lifetime_config.create_token(
+            Cow::Borrowed(aliri_tokens::AccessTokenRef::from_str(
+                access_token.expose(),
+            )),
+            None::<aliri_tokens::IdToken>,
+            aliri_clock::DurationSecs(expires_in),
+        ))
+    }
+}
+
+impl fmt::Debug for ClientCredentialsTokenSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ClientCredentialsTokenSource")
+            .field("token_url", &self.token_url)
+            .field("client_id", &self.client_id)
+            .field("client_secret", &self.client_secret)
+            .field("redaction", &self.redaction)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Apply a [`RedactionPolicy`] to an error that can carry the request URL or part of
+/// the response body.
+trait Redact {
+    fn redact(self, redaction: RedactionPolicy) -> Self;
+}
+
+impl Redact for reqwest::Error {
+    fn redact(mut self, redaction: RedactionPolicy) -> Self {
+        if let Some(url) = self.url_mut() {
+            *url = redaction.url(url);
+        }
+        self
+    }
+}
+
+impl Redact for reqwest_middleware::Error {
+    fn redact(self, redaction: RedactionPolicy) -> Self {
+        match self {
+            reqwest_middleware::Error::Reqwest(e) => {
+                reqwest_middleware::Error::Reqwest(e.redact(redaction))
+            }
+            e @ reqwest_middleware::Error::Middleware(_) => e,
+        }
+    }
+}
+
+impl Redact for serde_json::Error {
+    fn redact(self, redaction: RedactionPolicy) -> Self {
+        redaction.json_error(self)
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum SharesightReqwestError {
     #[error("Http request returned non-success status code\n{0} {1}\n{2}")]
@@ -173,6 +487,14 @@ pub enum SharesightReqwestError {
     TokenRequestError(#[from] aliri_tokens::sources::oauth2::TokenRequestError),
     #[error("IO error occurred\n{0:?}")]
     IoError(#[from] std::io::Error),
+    #[error("Invalid host\n{0}")]
+    BaseUrl(#[from] sharesight_types::ParseBaseUrlError),
+    #[error("Invalid url\n{0}")]
+    Url(#[from] url::ParseError),
+    #[error("Link doesn't point to the endpoint\n{0} {1}")]
+    UnexpectedLink(reqwest::Url, &'static str),
+    #[error("Link isn't to the API server\n{0}")]
+    ForeignLink(reqwest::Url),
 }
 
 #[derive(Debug)]
@@ -255,7 +577,210 @@ impl NameIndexItem for CashAccountsListCashAccountsSuccess {
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct ClientCredentials {
+    /// The API host, e.g. `api.sharesight.com`, or a base url such as
+    /// `http://localhost:8080/prefix`. See [`BaseUrl`].
     pub host: String,
     pub client_id: aliri_tokens::ClientId,
-    pub client_secret: aliri_tokens::ClientSecret,
+    pub client_secret: Secret,
+}
+
+impl ClientCredentials {
+    pub fn base_url(&self) -> Result<BaseUrl, sharesight_types::ParseBaseUrlError> {
+        self.host.parse()
+    }
+}
+
+/// A secret string, such as a client secret or token, that is zeroized when dropped
+/// and is never shown by `Debug` or `Display`.
+#[derive(Clone, Default, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct Secret(Zeroizing<String>);
+
+impl Secret {
+    pub fn new(secret: impl Into<String>) -> Self {
+        Self(Zeroizing::new(secret.into()))
+    }
+
+    /// Access the secret value. Take care not to log or otherwise copy the result.
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for Secret {
+    fn from(secret: String) -> Self {
+        Self::new(secret)
+    }
+}
+
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(RedactionPolicy::REDACTED)
+    }
+}
+
+impl fmt::Display for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(RedactionPolicy::REDACTED)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base_url_match() {
+        use predicates::Predicate;
+
+        let matches = |base_url: &str, url: &str| {
+            BaseUrlMatch(base_url.parse().unwrap()).eval(&reqwest::Request::new(
+                reqwest::Method::GET,
+                reqwest::Url::parse(url).unwrap(),
+            ))
+        };
+
+        assert!(matches(
+            "api.sharesight.com",
+            "https://api.sharesight.com/api/v3/portfolios"
+        ));
+        assert!(!matches(
+            "api.sharesight.com",
+            "http://api.sharesight.com/api/v3/portfolios"
+        ));
+        assert!(!matches(
+            "api.sharesight.com",
+            "https://example.com/api/v3/portfolios"
+        ));
+        assert!(matches(
+            "http://localhost:8080/stub",
+            "http://localhost:8080/stub/api/v3/portfolios"
+        ));
+        assert!(!matches(
+            "http://localhost:8080/stub",
+            "http://localhost:8080/stubborn/api/v3/portfolios"
+        ));
+        assert!(!matches(
+            "http://localhost:8080/stub",
+            "http://localhost:8081/stub/api/v3/portfolios"
+        ));
+        assert!(matches(
+            "api.sharesight.com",
+            "https://api.sharesight.com:443/api/v3/portfolios"
+        ));
+        assert!(matches(
+            "https://api.sharesight.com:443",
+            "https://api.sharesight.com/api/v3/portfolios"
+        ));
+        assert!(matches(
+            "API.Sharesight.com",
+            "https://api.sharesight.com/api/v3/portfolios"
+        ));
+    }
+
+    #[tokio::test]
+    async fn follow_checks_link() {
+        let client = Client {
+            client: reqwest_middleware::ClientBuilder::new(reqwest::Client::new()).build(),
+            base_url: BaseUrl::https("api.sharesight.com"),
+            redaction: RedactionPolicy::default(),
+        };
+        let follow = |link: &str| {
+            let link = link.parse::<Link>().unwrap();
+            let client = &client;
+            async move {
+                client
+                    .follow::<sharesight_types::v2::PortfolioShow>(&link)
+                    .await
+            }
+        };
+
+        for link in [
+            "https://example.com/api/v2/portfolios/1",
+            "http://api.sharesight.com/api/v2/portfolios/1",
+            "https://api.sharesight.com:8443/api/v2/portfolios/1",
+        ] {
+            assert!(
+                matches!(
+                    follow(link).await,
+                    Err(SharesightReqwestError::ForeignLink(_))
+                ),
+                "{link}"
+            );
+        }
+        assert!(matches!(
+            follow("https://api.sharesight.com/api/v3/portfolios/1").await,
+            Err(SharesightReqwestError::UnexpectedLink(
+                _,
+                "/portfolios/{id}.json"
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn request_error_url_is_redacted() {
+        let client = |redaction| Client {
+            client: reqwest_middleware::ClientBuilder::new(reqwest::Client::new()).build(),
+            base_url: "http://127.0.0.1:1".parse().unwrap(),
+            redaction,
+        };
+        let link = "http://127.0.0.1:1/api/v2/portfolios/1.json?token=hunter2"
+            .parse::<Link>()
+            .unwrap();
+
+        let error = client(RedactionPolicy::Fields)
+            .follow::<sharesight_types::v2::PortfolioShow>(&link)
+            .await
+            .unwrap_err();
+        let SharesightReqwestError::ReqwestMiddleware(reqwest_middleware::Error::Reqwest(e)) =
+            &error
+        else {
+            panic!("{error:?}");
+        };
+        assert_eq!(
+            Some("http://127.0.0.1:1/api/v2/portfolios/1.json"),
+            e.url().map(|u| u.as_str())
+        );
+        assert!(!format!("{error} {error:?}").contains("hunter2"));
+
+        let error = client(RedactionPolicy::Off)
+            .follow::<sharesight_types::v2::PortfolioShow>(&link)
+            .await
+            .unwrap_err();
+        assert!(format!("{error}").contains("hunter2"));
+    }
+
+    #[test]
+    fn token_source_keeps_secret() {
+        let source = ClientCredentialsTokenSource {
+            client: reqwest::Client::new(),
+            token_url: reqwest::Url::parse("https://api.sharesight.com/oauth2/token").unwrap(),
+            client_id: "id".into(),
+            client_secret: "hunter2".to_string().into(),
+            lifetime_config: aliri_tokens::TokenLifetimeConfig::default(),
+            redaction: RedactionPolicy::default(),
+        };
+
+        assert!(!format!("{:?}", source).contains("hunter2"));
+        assert_eq!(
+            r#"{"grant_type":"client_credentials","client_id":"id","client_secret":"hunter2"}"#,
+            serde_json::to_string(&source.request()).unwrap(),
+        );
+    }
+
+    #[test]
+    fn secret_is_not_printed() {
+        let credentials = ClientCredentials {
+            host: "api.sharesight.com".to_string(),
+            client_id: "id".into(),
+            client_secret: "hunter2".to_string().into(),
+        };
+
+        assert!(!format!("{:?}", credentials).contains("hunter2"));
+        assert!(!format!("{:#?}", credentials).contains("hunter2"));
+        assert_eq!(
+            r#"{"host":"api.sharesight.com","client_id":"id","client_secret":"hunter2"}"#,
+            serde_json::to_string(&credentials).unwrap(),
+        );
+    }
 }
@@ -1,1363 +1,1549 @@
+use std::fmt;
+
 use serde::{Deserialize, Serialize};
 
-#[allow(clippy::upper_case_acronyms)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
-pub enum Market {
-    /// New Zealand Stock Exchange
-    NZX,
-    /// Australian Stock Exchange
-    ASX,
-    /// New York Stock Exchange
-    NYSE,
-    /// NASDAQ
-    NASDAQ,
-    /// London Stock Exchange
-    LSE,
-    /// Australian Fund
-    FundAU,
-    /// New Zealand Fund
-    FundNZ,
-    /// ASX mFund
-    #[serde(rename = "mFund")]
-    MFund,
-    /// Euronext St
-    EURONEXT,
-    /// Canadian Securities Exchange
-    CNSX,
-    /// Toronto Stock Exchange
-    TSE,
-    /// Toronto Venture Exchange
-    CVE,
-    /// Hong Kong Stock Exchange
-    HKG,
-    /// Other Market
-    OTHER,
-    /// Singapore Exchange
-    SGX,
-    /// Johannesburg Stock Exchange
-    JSE,
-    /// Deutsche Boerse
-    FRA,
-    /// Swiss Exchange
-    SWX,
-    /// Tokyo Stock Exchange
-    TYO,
-    /// Borsa Italiana Milan
-    BIT,
-    /// Bolsa de Madrid
-    BME,
-    /// Bombay Stock Exchange
-    BSE,
-    /// National Stock Exchange of India
-    NSE,
-    /// Foreign Exchange Currency
-    FX,
-    /// Canadian Fund
-    FundCA,
-    /// NYSE American (AMEX)
-    AMEX,
-    /// OTC Bulletin Board
-    OTCBB,
-    /// Other OTC
-    OTC,
-    /// Shenzhen Stock Exchange
-    SHE,
-    /// Korea Exchange
-    KRX,
-    /// Taiwan Stock Exchange
-    TAI,
-    /// NASDAQ OMX Group MFQS
-    MFQS,
-    /// CBOE BATS
-    BATS,
-    /// UK Fund
-    FundUK,
-    /// Nasdaq Nordic Stockholm
-    STO,
-    /// Nasdaq Nordic Copenhagen
-    CSE,
-    /// Nasdaq Nordic Iceland
-    ICE,
-    /// Nasdaq Nordic Helsinki
-    HEL,
-    /// Oslo Stock Exchange
-    OSL,
-    /// Bursa Malaysia
-    KLS,
-    /// Shanghai Stock Exchange
-    SHG,
-    /// Euronext Dublin
-    DUB,
-    /// Moscow Exchange
-    MISX,
-    /// Stock Exchange of Thailand
-    BKK,
-    /// Warsaw Stock Exchange
-    WAR,
-    /// Korea Exchange (KOSDAQ)
-    KOSDAQ,
-    /// Tel Aviv Stock Exchange
-    TLV,
-    /// Budapest Stock Exchange
-    BDP,
-    /// NEO Exchange
-    NEO,
-    /// US Fund
-    FundUS,
-    /// Prague Stock Exchange
-    PSE,
-    /// Athens Stock Exchange
-    ASE,
-    /// B3 BOVESPA
-    BVMF,
-    /// Bolsa Mexicana de Valores
-    BMV,
-    /// Bolsa de Valores de Lima
-    BVL,
-    /// Cryptocurrency
-    CRYPTO,
-    /// Indonesia Stock Exchange
-    IDX,
-    /// Bucharest Stock Exchange
-    BVB,
-    /// Irish Fund
-    FundIE,
-    /// Vienna Stock Exchange
-    VIE,
-    /// Luxembourg Fund
-    FundLU,
-    /// Philippine Stock Exchange
-    PHS,
-    /// Goldie Wealth
-    GOLDIE,
-    /// Borsa Istanbul
-    BIST,
-    /// CBOE Australia - Limit Venue
-    CXA,
-    /// Deutsche Börse Xetra
-    XETR,
-    /// Precious Metals
-    METAL,
+use crate::types_prelude::string_enum;
+
+/// The error returned when parsing a code or other string value that isn't known.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseCodeError {
+    pub(crate) kind: &'static str,
+    pub(crate) code: String,
+}
+
+impl fmt::Display for ParseCodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown {} value: {:?}", self.kind, self.code)
+    }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum TradeDescription {
-    /// Buy
-    Buy,
-    /// Sell
-    Sell,
-    /// Split
-    Split,
-    /// Bonus
-    Bonus,
-    /// Consolidation
-    Consold,
-    /// Cancellation
-    Cancel,
-    /// Return of Capital
-    CapitalReturn,
-    /// Opening Balance
-    OpeningBalance,
-    /// Adjust Cost Base
-    AdjustCostBase,
-    /// Merge (Cancel)
-    MergeCancel,
-    /// Merge (Buy)
-    MergeBuy,
-    /// Capital Call
-    CapitalCall,
+impl std::error::Error for ParseCodeError {}
+
+string_enum! {
+    #[allow(clippy::upper_case_acronyms)]
+    pub enum Market {
+        /// New Zealand Stock Exchange
+        NZX = "NZX",
+        /// Australian Stock Exchange
+        ASX = "ASX",
+        /// New York Stock Exchange
+        NYSE = "NYSE",
+        /// NASDAQ
+        NASDAQ = "NASDAQ",
+        /// London Stock Exchange
+        LSE = "LSE",
+        /// Australian Fund
+        FundAU = "FundAU",
+        /// New Zealand Fund
+        FundNZ = "FundNZ",
+        /// ASX mFund
+        MFund = "mFund",
+        /// Euronext St
+        EURONEXT = "EURONEXT",
+        /// Canadian Securities Exchange
+        CNSX = "CNSX",
+        /// Toronto Stock Exchange
+        TSE = "TSE",
+        /// Toronto Venture Exchange
+        CVE = "CVE",
+        /// Hong Kong Stock Exchange
+        HKG = "HKG",
+        /// Other Market
+        OTHER = "OTHER",
+        /// Singapore Exchange
+        SGX = "SGX",
+        /// Johannesburg Stock Exchange
+        JSE = "JSE",
+        /// Deutsche Boerse
+        FRA = "FRA",
+        /// Swiss Exchange
+        SWX = "SWX",
+        /// Tokyo Stock Exchange
+        TYO = "TYO",
+        /// Borsa Italiana Milan
+        BIT = "BIT",
+        /// Bolsa de Madrid
+        BME = "BME",
+        /// Bombay Stock Exchange
+        BSE = "BSE",
+        /// National Stock Exchange of India
+        NSE = "NSE",
+        /// Foreign Exchange Currency
+        FX = "FX",
+        /// Canadian Fund
+        FundCA = "FundCA",
+        /// NYSE American (AMEX)
+        AMEX = "AMEX",
+        /// OTC Bulletin Board
+        OTCBB = "OTCBB",
+        /// Other OTC
+        OTC = "OTC",
+        /// Shenzhen Stock Exchange
+        SHE = "SHE",
+        /// Korea Exchange
+        KRX = "KRX",
+        /// Taiwan Stock Exchange
+        TAI = "TAI",
+        /// NASDAQ OMX Group MFQS
+        MFQS = "MFQS",
+        /// CBOE BATS
+        BATS = "BATS",
+        /// UK Fund
+        FundUK = "FundUK",
+        /// Nasdaq Nordic Stockholm
+        STO = "STO",
+        /// Nasdaq Nordic Copenhagen
+        CSE = "CSE",
+        /// Nasdaq Nordic Iceland
+        ICE = "ICE",
+        /// Nasdaq Nordic Helsinki
+        HEL = "HEL",
+        /// Oslo Stock Exchange
+        OSL = "OSL",
+        /// Bursa Malaysia
+        KLS = "KLS",
+        /// Shanghai Stock Exchange
+        SHG = "SHG",
+        /// Euronext Dublin
+        DUB = "DUB",
+        /// Moscow Exchange
+        MISX = "MISX",
+        /// Stock Exchange of Thailand
+        BKK = "BKK",
+        /// Warsaw Stock Exchange
+        WAR = "WAR",
+        /// Korea Exchange (KOSDAQ)
+        KOSDAQ = "KOSDAQ",
+        /// Tel Aviv Stock Exchange
+        TLV = "TLV",
+        /// Budapest Stock Exchange
+        BDP = "BDP",
+        /// NEO Exchange
+        NEO = "NEO",
+        /// US Fund
+        FundUS = "FundUS",
+        /// Prague Stock Exchange
+        PSE = "PSE",
+        /// Athens Stock Exchange
+        ASE = "ASE",
+        /// B3 BOVESPA
+        BVMF = "BVMF",
+        /// Bolsa Mexicana de Valores
+        BMV = "BMV",
+        /// Bolsa de Valores de Lima
+        BVL = "BVL",
+        /// Cryptocurrency
+        CRYPTO = "CRYPTO",
+        /// Indonesia Stock Exchange
+        IDX = "IDX",
+        /// Bucharest Stock Exchange
+        BVB = "BVB",
+        /// Irish Fund
+        FundIE = "FundIE",
+        /// Vienna Stock Exchange
+        VIE = "VIE",
+        /// Luxembourg Fund
+        FundLU = "FundLU",
+        /// Philippine Stock Exchange
+        PHS = "PHS",
+        /// Goldie Wealth
+        GOLDIE = "GOLDIE",
+        /// Borsa Istanbul
+        BIST = "BIST",
+        /// CBOE Australia - Limit Venue
+        CXA = "CXA",
+        /// Deutsche Börse Xetra
+        XETR = "XETR",
+        /// Precious Metals
+        METAL = "METAL",
+    }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
-pub enum PayoutDescription {
-    /// Dividend
-    #[serde(rename = "DIV")]
-    Dividend,
-    /// Capital Repayment
-    #[serde(rename = "REP")]
-    CapitalRepayment,
-    /// Interest Payment
-    #[serde(rename = "INT")]
-    InterestPayment,
-    /// Distribution
-    #[serde(rename = "DIS")]
-    Distribution,
+string_enum! {
+    pub enum TradeDescription {
+        /// Buy
+        Buy = "BUY",
+        /// Sell
+        Sell = "SELL",
+        /// Split
+        Split = "SPLIT",
+        /// Bonus
+        Bonus = "BONUS",
+        /// Consolidation
+        Consold = "CONSOLD",
+        /// Cancellation
+        Cancel = "CANCEL",
+        /// Return of Capital
+        CapitalReturn = "CAPITAL_RETURN",
+        /// Opening Balance
+        OpeningBalance = "OPENING_BALANCE",
+        /// Adjust Cost Base
+        AdjustCostBase = "ADJUST_COST_BASE",
+        /// Merge (Cancel)
+        MergeCancel = "MERGE_CANCEL",
+        /// Merge (Buy)
+        MergeBuy = "MERGE_BUY",
+        /// Capital Call
+        CapitalCall = "CAPITAL_CALL",
+    }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
-pub enum Country {
-    /// Afghanistan
-    #[serde(rename = "AF")]
-    Afghanistan,
-    /// Albania
-    #[serde(rename = "AL")]
-    Albania,
-    /// Algeria
-    #[serde(rename = "DZ")]
-    Algeria,
-    /// American Samoa
-    #[serde(rename = "AS")]
-    AmericanSamoa,
-    /// Andorra
-    #[serde(rename = "AD")]
-    Andorra,
-    /// Angola
-    #[serde(rename = "AO")]
-    Angola,
-    /// Anguilla
-    #[serde(rename = "AI")]
-    Anguilla,
-    /// Antarctica
-    #[serde(rename = "AQ")]
-    Antarctica,
-    /// Antigua and Barbuda
-    #[serde(rename = "AG")]
-    AntiguaAndBarbuda,
-    /// Argentina
-    #[serde(rename = "AR")]
-    Argentina,
-    /// Armenia
-    #[serde(rename = "AM")]
-    Armenia,
-    /// Aruba
-    #[serde(rename = "AW")]
-    Aruba,
-    /// Asia
-    #[serde(rename = "_AS")]
-    Asia,
-    /// Australia
-    #[serde(rename = "AU")]
-    Australia,
-    /// Austria
-    #[serde(rename = "AT")]
-    Austria,
-    /// Azerbaijan
-    #[serde(rename = "AZ")]
-    Azerbaijan,
-    /// Bahamas
-    #[serde(rename = "BS")]
-    Bahamas,
-    /// Bahrain
-    #[serde(rename = "BH")]
-    Bahrain,
-    /// Bangladesh
-    #[serde(rename = "BD")]
-    Bangladesh,
-    /// Barbados
-    #[serde(rename = "BB")]
-    Barbados,
-    /// Belarus
-    #[serde(rename = "BY")]
-    Belarus,
-    /// Belgium
-    #[serde(rename = "BE")]
-    Belgium,
-    /// Belize
-    #[serde(rename = "BZ")]
-    Belize,
-    /// Benin
-    #[serde(rename = "BJ")]
-    Benin,
-    /// Bermuda
-    #[serde(rename = "BM")]
-    Bermuda,
-    /// Bhutan
-    #[serde(rename = "BT")]
-    Bhutan,
-    /// Bitcoin
-    #[serde(rename = "XB")]
-    Bitcoin,
-    /// Bolivia
-    #[serde(rename = "BO")]
-    Bolivia,
-    /// Bonaire, Sint Eustatius and Saba
-    #[serde(rename = "BQ")]
-    BonaireSintEustatiusAndSaba,
-    /// Bosnia and Herzegovina
-    #[serde(rename = "BA")]
-    BosniaAndHerzegovina,
-    /// Botswana
-    #[serde(rename = "BW")]
-    Botswana,
-    /// Bouvet Island
-    #[serde(rename = "BV")]
-    BouvetIsland,
-    /// Brazil
-    #[serde(rename = "BR")]
-    Brazil,
-    /// British Indian Ocean Territory
-    #[serde(rename = "IO")]
-    BritishIndianOceanTerritory,
-    /// Brunei
-    #[serde(rename = "BN")]
-    Brunei,
-    /// Bulgaria
-    #[serde(rename = "BG")]
-    Bulgaria,
-    /// Burkina Faso
-    #[serde(rename = "BF")]
-    BurkinaFaso,
-    /// Burundi
-    #[serde(rename = "BI")]
-    Burundi,
-    /// Cambodia
-    #[serde(rename = "KH")]
-    Cambodia,
-    /// Cameroon
-    #[serde(rename = "CM")]
-    Cameroon,
-    /// Canada
-    #[serde(rename = "CA")]
-    Canada,
-    /// Cape Verde
-    #[serde(rename = "CV")]
-    CapeVerde,
-    /// Cayman Islands
-    #[serde(rename = "KY")]
-    CaymanIslands,
-    /// Central African Republic
-    #[serde(rename = "CF")]
-    CentralAfricanRepublic,
-    /// Chad
-    #[serde(rename = "TD")]
-    Chad,
-    /// Chile
-    #[serde(rename = "CL")]
-    Chile,
-    /// China
-    #[serde(rename = "CN")]
-    China,
-    /// China, (Offshore)
-    #[serde(rename = "_CN")]
-    ChinaOffshore,
-    /// Christmas Island
-    #[serde(rename = "CX")]
-    ChristmasIsland,
-    /// Cocos (Keeling) Islands
-    #[serde(rename = "CC")]
-    CocosKeelingIslands,
-    /// Colombia
-    #[serde(rename = "CO")]
-    Colombia,
-    /// Comoros
-    #[serde(rename = "KM")]
-    Comoros,
-    /// Congo Republic of the Democratic
-    #[serde(rename = "CG")]
-    CongoRepublicOfTheDemocratic,
-    /// Congo-Brazzaville
-    #[serde(rename = "CD")]
-    CongoBrazzaville,
-    /// Cook Islands
-    #[serde(rename = "CK")]
-    CookIslands,
-    /// Costa Rica
-    #[serde(rename = "CR")]
-    CostaRica,
-    /// Croatia (Hrvatska)
-    #[serde(rename = "HR")]
-    Croatia,
-    /// Cuba
-    #[serde(rename = "CU")]
-    Cuba,
-    /// Curaçao
-    #[serde(rename = "CW")]
-    Curaçao,
-    /// Cyprus
-    #[serde(rename = "CY")]
-    Cyprus,
-    /// Cyprus (pre-Euro)
-    #[serde(rename = "XCY")]
-    CyprusPreEuro,
-    /// Czech Republic
-    #[serde(rename = "CZ")]
-    CzechRepublic,
-    /// Denmark
-    #[serde(rename = "DK")]
-    Denmark,
-    /// Djibouti
-    #[serde(rename = "DJ")]
-    Djibouti,
-    /// Dominica
-    #[serde(rename = "DM")]
-    Dominica,
-    /// Dominican Republic
-    #[serde(rename = "DO")]
-    DominicanRepublic,
-    /// East Timor
-    #[serde(rename = "TP")]
-    EastTimorP,
-    /// East Timor
-    #[serde(rename = "TL")]
-    EastTimorL,
-    /// Ecuador
-    #[serde(rename = "EC")]
-    Ecuador,
-    /// Egypt
-    #[serde(rename = "EG")]
-    Egypt,
-    /// El Salvador
-    #[serde(rename = "SV")]
-    ElSalvador,
-    /// Equatorial Guinea
-    #[serde(rename = "GQ")]
-    EquatorialGuinea,
-    /// Eritrea
-    #[serde(rename = "ER")]
-    Eritrea,
-    /// Estonia
-    #[serde(rename = "EE")]
-    Estonia,
-    /// Estonia (pre-Euro)
-    #[serde(rename = "XEE")]
-    EstoniaPreEuro,
-    /// Ethiopia
-    #[serde(rename = "ET")]
-    Ethiopia,
-    /// Europe
-    #[serde(rename = "XS")]
-    Europe,
-    /// Falkland Islands (Malvinas)
-    #[serde(rename = "FK")]
-    FalklandIslands,
-    /// Faroe Islands
-    #[serde(rename = "FO")]
-    FaroeIslands,
-    /// Fiji
-    #[serde(rename = "FJ")]
-    Fiji,
-    /// Finland
-    #[serde(rename = "FI")]
-    Finland,
-    /// France
-    #[serde(rename = "FR")]
-    France,
-    /// French Guiana
-    #[serde(rename = "GF")]
-    FrenchGuiana,
-    /// French Polynesia
-    #[serde(rename = "PF")]
-    FrenchPolynesia,
-    /// French Southern Territories
-    #[serde(rename = "TF")]
-    FrenchSouthernTerritories,
-    /// Gabon
-    #[serde(rename = "GA")]
-    Gabon,
-    /// Gambia
-    #[serde(rename = "GM")]
-    Gambia,
-    /// Georgia
-    #[serde(rename = "GE")]
-    Georgia,
-    /// Germany
-    #[serde(rename = "DE")]
-    Germany,
-    /// Ghana
-    #[serde(rename = "GH")]
-    Ghana,
-    /// Gibraltar
-    #[serde(rename = "GI")]
-    Gibraltar,
-    /// Greece
-    #[serde(rename = "GR")]
-    Greece,
-    /// Greenland
-    #[serde(rename = "GL")]
-    Greenland,
-    /// Grenada
-    #[serde(rename = "GD")]
-    Grenada,
-    /// Guadeloupe
-    #[serde(rename = "GP")]
-    Guadeloupe,
-    /// Guam
-    #[serde(rename = "GU")]
-    Guam,
-    /// Guatemala
-    #[serde(rename = "GT")]
-    Guatemala,
-    /// Guernsey
-    #[serde(rename = "GG")]
-    Guernsey,
-    /// Guinea
-    #[serde(rename = "GN")]
-    Guinea,
-    /// Guinea-Bissau
-    #[serde(rename = "GW")]
-    GuineaBissau,
-    /// Guyana
-    #[serde(rename = "GY")]
-    Guyana,
-    /// Haiti
-    #[serde(rename = "HT")]
-    Haiti,
-    /// Heard and Mc Donald Islands
-    #[serde(rename = "HM")]
-    HeardAndMcDonaldIslands,
-    /// Honduras
-    #[serde(rename = "HN")]
-    Honduras,
-    /// Hong Kong
-    #[serde(rename = "HK")]
-    HongKong,
-    /// Hungary
-    #[serde(rename = "HU")]
-    Hungary,
-    /// Iceland
-    #[serde(rename = "IS")]
-    Iceland,
-    /// India
-    #[serde(rename = "IN")]
-    India,
-    /// Indonesia
-    #[serde(rename = "ID")]
-    Indonesia,
-    /// International
-    #[serde(rename = "_IN")]
-    International,
-    /// Iran (Islamic Republic of)
-    #[serde(rename = "IR")]
-    Iran,
-    /// Iraq
-    #[serde(rename = "IQ")]
-    Iraq,
-    /// Ireland
-    #[serde(rename = "IE")]
-    Ireland,
-    /// Isle of Man
-    #[serde(rename = "IM")]
-    IsleOfMan,
-    /// Israel
-    #[serde(rename = "IL")]
-    Israel,
-    /// Italy
-    #[serde(rename = "IT")]
-    Italy,
-    /// Ivory Coast
-    #[serde(rename = "CI")]
-    IvoryCoast,
-    /// Jamaica
-    #[serde(rename = "JM")]
-    Jamaica,
-    /// Japan
-    #[serde(rename = "JP")]
-    Japan,
-    /// Jersey
-    #[serde(rename = "JE")]
-    Jersey,
-    /// Jordan
-    #[serde(rename = "JO")]
-    Jordan,
-    /// Kazakhstan
-    #[serde(rename = "KZ")]
-    Kazakhstan,
-    /// Kenya
-    #[serde(rename = "KE")]
-    Kenya,
-    /// Kiribati
-    #[serde(rename = "KI")]
-    Kiribati,
-    /// Kuwait
-    #[serde(rename = "KW")]
-    Kuwait,
-    /// Kyrgyzstan
-    #[serde(rename = "KG")]
-    Kyrgyzstan,
-    /// Lao Peoples Democratic Republic
-    #[serde(rename = "LA")]
-    LaoPeoplesDemocraticRepublic,
-    /// Latin America
-    #[serde(rename = "_LA")]
-    LatinAmerica,
-    /// Latvia
-    #[serde(rename = "LV")]
-    Latvia,
-    /// Latvia (pre-Euro)
-    #[serde(rename = "XLV")]
-    LatviaPreEuro,
-    /// Lebanon
-    #[serde(rename = "LB")]
-    Lebanon,
-    /// Lesotho
-    #[serde(rename = "LS")]
-    Lesotho,
-    /// Liberia
-    #[serde(rename = "LR")]
-    Liberia,
-    /// Libyan Arab Jamahiriya
-    #[serde(rename = "LY")]
-    LibyanArabJamahiriya,
-    /// Liechtenstein
-    #[serde(rename = "LI")]
-    Liechtenstein,
-    /// Lithuania
-    #[serde(rename = "LT")]
-    Lithuania,
-    /// Lithuania (pre-Euro)
-    #[serde(rename = "XLT")]
-    LithuaniaPreEuro,
-    /// Luxembourg
-    #[serde(rename = "LU")]
-    Luxembourg,
-    /// Macau
-    #[serde(rename = "MO")]
-    Macau,
-    /// Madagascar
-    #[serde(rename = "MG")]
-    Madagascar,
-    /// Malawi
-    #[serde(rename = "MW")]
-    Malawi,
-    /// Malaysia
-    #[serde(rename = "MY")]
-    Malaysia,
-    /// Maldives
-    #[serde(rename = "MV")]
-    Maldives,
-    /// Mali
-    #[serde(rename = "ML")]
-    Mali,
-    /// Malta
-    #[serde(rename = "MT")]
-    Malta,
-    /// Malta (pre-Euro)
-    #[serde(rename = "XMT")]
-    MaltaPreEuro,
-    /// Marshall Islands
-    #[serde(rename = "MH")]
-    MarshallIslands,
-    /// Martinique
-    #[serde(rename = "MQ")]
-    Martinique,
-    /// Mauritania
-    #[serde(rename = "MR")]
-    Mauritania,
-    /// Mauritius
-    #[serde(rename = "MU")]
-    Mauritius,
-    /// Mayotte
-    #[serde(rename = "YT")]
-    Mayotte,
-    /// Mexico
-    #[serde(rename = "MX")]
-    Mexico,
-    /// Micronesia (Federated States of)
-    #[serde(rename = "FM")]
-    Micronesia,
-    /// Middle East
-    #[serde(rename = "_ME")]
-    MiddleEast,
-    /// Moldova (Republic of)
-    #[serde(rename = "MD")]
-    MoldovaRepublicOf,
-    /// Monaco
-    #[serde(rename = "MC")]
-    Monaco,
-    /// Mongolia
-    #[serde(rename = "MN")]
-    Mongolia,
-    /// Montenegro
-    #[serde(rename = "ME")]
-    Montenegro,
-    /// Montserrat
-    #[serde(rename = "MS")]
-    Montserrat,
-    /// Morocco
-    #[serde(rename = "MA")]
-    Morocco,
-    /// Mozambique
-    #[serde(rename = "MZ")]
-    Mozambique,
-    /// Myanmar
-    #[serde(rename = "MM")]
-    Myanmar,
-    /// Namibia
-    #[serde(rename = "NA")]
-    Namibia,
-    /// Nauru
-    #[serde(rename = "NR")]
-    Nauru,
-    /// Nepal
-    #[serde(rename = "NP")]
-    Nepal,
-    /// Netherlands
-    #[serde(rename = "NL")]
-    Netherlands,
-    /// Netherlands Antilles
-    #[serde(rename = "AN")]
-    NetherlandsAntilles,
-    /// New Caledonia
-    #[serde(rename = "NC")]
-    NewCaledonia,
-    /// New Zealand
-    #[serde(rename = "NZ")]
-    NewZealand,
-    /// Nicaragua
-    #[serde(rename = "NI")]
-    Nicaragua,
-    /// Niger
-    #[serde(rename = "NE")]
-    Niger,
-    /// Nigeria
-    #[serde(rename = "NG")]
-    Nigeria,
-    /// Niue
-    #[serde(rename = "NU")]
-    Niue,
-    /// Norfolk Island
-    #[serde(rename = "NF")]
-    NorfolkIsland,
-    /// North Korea
-    #[serde(rename = "KP")]
-    NorthKorea,
-    /// North Macedonia
-    #[serde(rename = "MK")]
-    NorthMacedonia,
-    /// Northern Mariana Islands
-    #[serde(rename = "MP")]
-    NorthernMarianaIslands,
-    /// Norway
-    #[serde(rename = "NO")]
-    Norway,
-    /// Oman
-    #[serde(rename = "OM")]
-    Oman,
-    /// Pakistan
-    #[serde(rename = "PK")]
-    Pakistan,
-    /// Palau
-    #[serde(rename = "PW")]
-    Palau,
-    /// Panama
-    #[serde(rename = "PA")]
-    Panama,
-    /// Papua New Guinea
-    #[serde(rename = "PG")]
-    PapuaNewGuinea,
-    /// Paraguay
-    #[serde(rename = "PY")]
-    Paraguay,
-    /// Peru
-    #[serde(rename = "PE")]
-    Peru,
-    /// Philippines
-    #[serde(rename = "PH")]
-    Philippines,
-    /// Pitcairn
-    #[serde(rename = "PN")]
-    Pitcairn,
-    /// Poland
-    #[serde(rename = "PL")]
-    Poland,
-    /// Portugal
-    #[serde(rename = "PT")]
-    Portugal,
-    /// Puerto Rico
-    #[serde(rename = "PR")]
-    PuertoRico,
-    /// Qatar
-    #[serde(rename = "QA")]
-    Qatar,
-    /// Reunion
-    #[serde(rename = "RE")]
-    Reunion,
-    /// Romania
-    #[serde(rename = "RO")]
-    Romania,
-    /// Russian Federation
-    #[serde(rename = "RU")]
-    RussianFederation,
-    /// Rwanda
-    #[serde(rename = "RW")]
-    Rwanda,
-    /// Saint Barthélemy
-    #[serde(rename = "BL")]
-    SaintBarthélemy,
-    /// Saint Helena, Ascension and Tristan da Cunha
-    #[serde(rename = "SH")]
-    SaintHelena,
-    /// Saint Kitts
-    #[serde(rename = "KN")]
-    SaintKitts,
-    /// Saint Lucia
-    #[serde(rename = "LC")]
-    SaintLucia,
-    /// Saint Martin
-    #[serde(rename = "MF")]
-    SaintMartin,
-    /// Saint Pierre and Miquelon
-    #[serde(rename = "PM")]
-    SaintPierreAndMiquelon,
-    /// Saint Vincent Grenadines
-    #[serde(rename = "VC")]
-    SaintVincentGrenadines,
-    /// Samoa
-    #[serde(rename = "WS")]
-    Samoa,
-    /// San Marino
-    #[serde(rename = "SM")]
-    SanMarino,
-    /// Sao Tome and Principe
-    #[serde(rename = "ST")]
-    SaoTomeAndPrincipe,
-    /// Saudi Arabia
-    #[serde(rename = "SA")]
-    SaudiArabia,
-    /// Senegal
-    #[serde(rename = "SN")]
-    Senegal,
-    /// Serbia
-    #[serde(rename = "RS")]
-    Serbia,
-    /// Seychelles
-    #[serde(rename = "SC")]
-    Seychelles,
-    /// Sierra Leone
-    #[serde(rename = "SL")]
-    SierraLeone,
-    /// Singapore
-    #[serde(rename = "SG")]
-    Singapore,
-    /// Sint Maarten
-    #[serde(rename = "SX")]
-    SintMaarten,
-    /// Slovakia (Slovak Republic)
-    #[serde(rename = "SK")]
-    Slovakia,
-    /// Slovakia (pre-Euro)
-    #[serde(rename = "XSK")]
-    SlovakiaPreEuro,
-    /// Slovenia
-    #[serde(rename = "SI")]
-    Slovenia,
-    /// Solomon Islands
-    #[serde(rename = "SB")]
-    SolomonIslands,
-    /// Somalia
-    #[serde(rename = "SO")]
-    Somalia,
-    /// South Africa
-    #[serde(rename = "ZA")]
-    SouthAfrica,
-    /// South Georgia and the South Sandwich Islands
-    #[serde(rename = "GS")]
-    SouthGeorgiaAndTheSouthSandwichIslands,
-    /// South Korea
-    #[serde(rename = "KR")]
-    SouthKorea,
-    /// South Sudan
-    #[serde(rename = "SS")]
-    SouthSudan,
-    /// Spain
-    #[serde(rename = "ES")]
-    Spain,
-    /// Sri Lanka
-    #[serde(rename = "LK")]
-    SriLanka,
-    /// State of Palestine
-    #[serde(rename = "PS")]
-    StateOfPalestine,
-    /// Sudan
-    #[serde(rename = "SD")]
-    Sudan,
-    /// Suriname
-    #[serde(rename = "SR")]
-    Suriname,
-    /// Svalbard and Jan Mayen Islands
-    #[serde(rename = "SJ")]
-    SvalbardAndJanMayenIslands,
-    /// Swaziland
-    #[serde(rename = "SZ")]
-    Swaziland,
-    /// Sweden
-    #[serde(rename = "SE")]
-    Sweden,
-    /// Switzerland
-    #[serde(rename = "CH")]
-    Switzerland,
-    /// Syrian Arab Republic
-    #[serde(rename = "SY")]
-    SyrianArabRepublic,
-    /// Taiwan
-    #[serde(rename = "TW")]
-    Taiwan,
-    /// Tajikistan
-    #[serde(rename = "TJ")]
-    Tajikistan,
-    /// Tanzania
-    #[serde(rename = "TZ")]
-    Tanzania,
-    /// Thailand
-    #[serde(rename = "TH")]
-    Thailand,
-    /// Togo
-    #[serde(rename = "TG")]
-    Togo,
-    /// Tokelau
-    #[serde(rename = "TK")]
-    Tokelau,
-    /// Tonga
-    #[serde(rename = "TO")]
-    Tonga,
-    /// Trinidad and Tobago
-    #[serde(rename = "TT")]
-    TrinidadAndTobago,
-    /// Tunisia
-    #[serde(rename = "TN")]
-    Tunisia,
-    /// Turkey
-    #[serde(rename = "TR")]
-    Turkey,
-    /// Turkmenistan
-    #[serde(rename = "TM")]
-    Turkmenistan,
-    /// Turks and Caicos Islands
-    #[serde(rename = "TC")]
-    TurksAndCaicosIslands,
-    /// Tuvalu
-    #[serde(rename = "TV")]
-    Tuvalu,
-    /// Uganda
-    #[serde(rename = "UG")]
-    Uganda,
-    /// Ukraine
-    #[serde(rename = "UA")]
-    Ukraine,
-    /// United Arab Emirates
-    #[serde(rename = "AE")]
-    UnitedArabEmirates,
-    /// United Kingdom
-    #[serde(rename = "GB")]
-    UnitedKingdom,
-    /// United States
-    #[serde(rename = "US")]
-    UnitedStates,
-    /// United States Minor Outlying Islands
-    #[serde(rename = "UM")]
-    UnitedStatesMinorOutlyingIslands,
-    /// Unknown
-    #[serde(rename = "UN")]
-    Unknown,
-    /// Uruguay
-    #[serde(rename = "UY")]
-    Uruguay,
-    /// Uzbekistan
-    #[serde(rename = "UZ")]
-    Uzbekistan,
-    /// Vanuatu
-    #[serde(rename = "VU")]
-    Vanuatu,
-    /// Vatican City State (Holy See)
-    #[serde(rename = "VA")]
-    VaticanCityState,
-    /// Venezuela
-    #[serde(rename = "VE")]
-    Venezuela,
-    /// Vietnam
-    #[serde(rename = "VN")]
-    Vietnam,
-    /// Virgin Islands (British)
-    #[serde(rename = "VG")]
-    VirginIslandsBritish,
-    /// Virgin Islands (US)
-    #[serde(rename = "VI")]
-    VirginIslandsUS,
-    /// Wallis and Futuna Islands
-    #[serde(rename = "WF")]
-    WallisAndFutunaIslands,
-    /// Western Sahara
-    #[serde(rename = "EH")]
-    WesternSahara,
-    /// Yemen
-    #[serde(rename = "YE")]
-    Yemen,
-    /// Zambia
-    #[serde(rename = "ZM")]
-    Zambia,
-    /// Zimbabwe
-    #[serde(rename = "ZW")]
-    Zimbabwe,
-    /// Åland Islands
-    #[serde(rename = "AX")]
-    ÅlandIslands,
+string_enum! {
+    pub enum PayoutDescription {
+        /// Dividend
+        Dividend = "DIV",
+        /// Capital Repayment
+        CapitalRepayment = "REP",
+        /// Interest Payment
+        InterestPayment = "INT",
+        /// Distribution
+        Distribution = "DIS",
+    }
 }
 
-#[allow(clippy::upper_case_acronyms)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
-pub enum Currency {
-    /// Afghani
-    AFN,
-    /// Albanian Lek
-    ALL,
-    /// Algerian Dinar
-    DZD,
-    /// Argentine Peso
-    ARS,
-    /// Armenian Dram
-    AMD,
-    /// Australian Dollars
-    AUD,
-    /// Azerbaijani Manat
-    AZN,
-    /// Bahamian Dollar
-    BSD,
-    /// Bahraini Dinar
-    BHD,
-    /// Baht
-    THB,
-    /// Barbadian Dollar
-    BBD,
-    /// Belarusian Ruble
-    BYN,
-    /// Belizean Dollar
-    BZD,
-    /// Bermudian Dollar
-    BMD,
-    /// Bitcoin
-    #[serde(alias = "XBT")]
-    BTC,
-    /// Bitshares
-    BTS,
-    /// Bolivar
-    VEF,
-    /// Boliviano
-    BOB,
-    /// Bosnia and Herzegovina Convertible Mark
-    BAM,
-    /// Brazil Real
-    BRL,
-    /// Brunei Dollar
-    BND,
-    /// Burundi Franc
-    BIF,
-    /// CFA Franc BCEAO
-    XOF,
-    /// CFA Franc BEAC
-    XAF,
-    /// CFP Franc
-    XPF,
-    /// Canadian Dollar
-    CAD,
-    /// Caymanian Dollar
-    KYD,
-    /// Chilean Peso
-    CLP,
-    /// Chinese Yuan (Offshore)
-    CNH,
-    /// Colombian Peso
-    COP,
-    /// Comoran Franc
-    KMF,
-    /// Congolese Frank
-    CDF,
-    /// Cordoba Oro
-    NIO,
-    /// Costa Rican Colon
-    CRC,
-    /// Croatian Kuna
-    HRK,
-    /// Cuban Peso
-    CUP,
-    /// Cypriot Pound
-    CYP,
-    /// Dalasi
-    GMD,
-    /// Danish Krone
-    DKK,
-    /// Dash
-    DAS,
-    /// Dinar
-    SDG,
-    /// Dirham
-    MAD,
-    /// Dirham
-    AED,
-    /// Djiboutian Franc
-    DJF,
-    /// Dobra
-    STD,
-    /// Dominican Peso
-    DOP,
-    /// Dong
-    VND,
-    /// East Caribbean Dollar
-    XCD,
-    /// Egyptian Pound
-    EGP,
-    /// Escudo
-    CVE,
-    /// Estonian Kroon
-    EEK,
-    /// Ethereum
-    ETH,
-    /// Ethiopian Birr
-    ETB,
-    /// Euros
-    EUR,
-    /// Falkland Pound
-    FKP,
-    /// Fijian Dollar
-    FJD,
-    /// Forint
-    HUF,
-    /// Gibraltar Pound
-    GIP,
-    /// Gourde
-    HTG,
-    /// Guinean Franc
-    GNF,
-    /// Guyanaese Dollar
-    GYD,
-    /// Hong Kong Dollar
-    HKD,
-    /// Hryvnia
-    UAH,
-    /// Icelandic Krona
-    ISK,
-    /// Indian Rupee
-    INR,
-    /// Indonesian Rupiah
-    IDR,
-    /// Iranian Rial
-    IRR,
-    /// Iraqi Dinar
-    IQD,
-    /// Jamaican Dollar
-    JMD,
-    /// Japanese Yen
-    JPY,
-    /// Jordanian Dinar
-    JOD,
-    /// Kazahstani Tenge
-    KZT,
-    /// Kenyan Shilling
-    KES,
-    /// Kina
-    PGK,
-    /// Kip
-    LAK,
-    /// Koruna
-    CZK,
-    /// Koruna
-    SKK,
-    /// Krona
-    SEK,
-    /// Kuwaiti Dinar
-    KWD,
-    /// Kwacha
-    ZMK,
-    /// Kyat
-    MMK,
-    /// Lari
-    GEL,
-    /// Lat
-    LVL,
-    /// Lebanese Pound
-    LBP,
-    /// Lempira
-    HNL,
-    /// Leone
-    SLL,
-    /// Leu
-    MDL,
-    /// Leu
-    RON,
-    /// Lev
-    BGN,
-    /// Liberian Dollar
-    LRD,
-    /// Libyan Dinar
-    LYD,
-    /// Lilangeni
-    SZL,
-    /// Lira
-    TRY,
-    /// Lita
-    LTL,
-    /// Litecoin
-    LTC,
-    /// Loti
-    LSL,
-    /// Macanese Pataca
-    MOP,
-    /// Macedonian Denar
-    MKD,
-    /// Malagasy Franc
-    MGA,
-    /// Malawian Kwacha
-    MWK,
-    /// Maldivian Rufiyaa
-    MVR,
-    /// Maltese Lira
-    MTL,
-    /// Manat
-    TMT,
-    /// Mauritian Rupee
-    MUR,
-    /// Metical
-    MZN,
-    /// Mexican Peso
-    MXN,
-    /// Monero
-    XMR,
-    /// Naira
-    NGN,
-    /// Namibian Dollar
-    NAD,
-    /// Nepalese Rupee
-    NPR,
-    /// Netherlands Antilles Guilder
-    ANG,
-    /// New Taiwan Dollar
-    TWD,
-    /// New Zealand Dollars
-    NZD,
-    /// North Korean Won
-    KPW,
-    /// Norwegian Krone
-    NOK,
-    /// Nuevo Sol
-    PEN,
-    /// Omani Rial
-    OMR,
-    /// Ouguiya
-    MRO,
-    /// Pakistani Rupee
-    PKR,
-    /// Palanga
-    TOP,
-    /// Panamaian Balboa
-    PAB,
-    /// Paraguayan Guaraní
-    PYG,
-    /// Philippine Peso
-    PHP,
-    /// Pounds Sterling
-    GBP,
-    /// Pula
-    BWP,
-    /// Qatari Riyal
-    QAR,
-    /// Quetzal
-    GTQ,
-    /// Rand
-    ZAR,
-    /// Rial
-    YER,
-    /// Riel
-    KHR,
-    /// Ringgit
-    MYR,
-    /// Ripple
-    XRP,
-    /// Riyal
-    SAR,
-    /// Ruble
-    RUB,
-    /// Rwanda Franc
-    RWF,
-    /// Salvadoran Colon
-    SVC,
-    /// Serbian Dinar
-    RSD,
-    /// Seychellois Rupee
-    SCR,
-    /// Shekel
-    ILS,
-    /// Shilling
-    SOS,
-    /// Shilling
-    TZS,
-    /// Shilling
-    UGX,
-    /// Singapore Dollar
-    SGD,
-    /// Solomon Islands Dollar
-    SBD,
-    /// Som
-    KGS,
-    /// South Korean Won
-    KRW,
-    /// Sri Lankan Rupee
-    LKR,
-    /// Stellar
-    XLM,
-    /// Sucre
-    ECS,
-    /// Surinamese Guilder
-    SRD,
-    /// Swiss Franc
-    CHF,
-    /// Syrian Pound
-    SYP,
-    /// Tajikistan Ruble
-    TJS,
-    /// Taka
-    BDT,
-    /// Trinidad and Tobago Dollar
-    TTD,
-    /// Tugrik
-    MNT,
-    /// Tunisian Dinar
-    TND,
-    /// United States Dollar
-    USD,
-    /// Uruguayan Peso
-    UYU,
-    /// Uzbekistani Soʻm
-    UZS,
-    /// Vanuatu Vatu
-    VUV,
-    /// Yuan Renminbi
-    CNY,
-    /// Zimbabwe Dollar
-    ZWD,
-    /// Zloty
-    PLN,
-    // Tether (USDT)
-    USDT,
+string_enum! {
+    pub enum Country {
+        /// Afghanistan
+        Afghanistan = "AF",
+        /// Albania
+        Albania = "AL",
+        /// Algeria
+        Algeria = "DZ",
+        /// American Samoa
+        AmericanSamoa = "AS",
+        /// Andorra
+        Andorra = "AD",
+        /// Angola
+        Angola = "AO",
+        /// Anguilla
+        Anguilla = "AI",
+        /// Antarctica
+        Antarctica = "AQ",
+        /// Antigua and Barbuda
+        AntiguaAndBarbuda = "AG",
+        /// Argentina
+        Argentina = "AR",
+        /// Armenia
+        Armenia = "AM",
+        /// Aruba
+        Aruba = "AW",
+        /// Asia
+        Asia = "_AS",
+        /// Australia
+        Australia = "AU",
+        /// Austria
+        Austria = "AT",
+        /// Azerbaijan
+        Azerbaijan = "AZ",
+        /// Bahamas
+        Bahamas = "BS",
+        /// Bahrain
+        Bahrain = "BH",
+        /// Bangladesh
+        Bangladesh = "BD",
+        /// Barbados
+        Barbados = "BB",
+        /// Belarus
+        Belarus = "BY",
+        /// Belgium
+        Belgium = "BE",
+        /// Belize
+        Belize = "BZ",
+        /// Benin
+        Benin = "BJ",
+        /// Bermuda
+        Bermuda = "BM",
+        /// Bhutan
+        Bhutan = "BT",
+        /// Bitcoin
+        Bitcoin = "XB",
+        /// Bolivia
+        Bolivia = "BO",
+        /// Bonaire, Sint Eustatius and Saba
+        BonaireSintEustatiusAndSaba = "BQ",
+        /// Bosnia and Herzegovina
+        BosniaAndHerzegovina = "BA",
+        /// Botswana
+        Botswana = "BW",
+        /// Bouvet Island
+        BouvetIsland = "BV",
+        /// Brazil
+        Brazil = "BR",
+        /// British Indian Ocean Territory
+        BritishIndianOceanTerritory = "IO",
+        /// Brunei
+        Brunei = "BN",
+        /// Bulgaria
+        Bulgaria = "BG",
+        /// Burkina Faso
+        BurkinaFaso = "BF",
+        /// Burundi
+        Burundi = "BI",
+        /// Cambodia
+        Cambodia = "KH",
+        /// Cameroon
+        Cameroon = "CM",
+        /// Canada
+        Canada = "CA",
+        /// Cape Verde
+        CapeVerde = "CV",
+        /// Cayman Islands
+        CaymanIslands = "KY",
+        /// Central African Republic
+        CentralAfricanRepublic = "CF",
+        /// Chad
+        Chad = "TD",
+        /// Chile
+        Chile = "CL",
+        /// China
+        China = "CN",
+        /// China, (Offshore)
+        ChinaOffshore = "_CN",
+        /// Christmas Island
+        ChristmasIsland = "CX",
+        /// Cocos (Keeling) Islands
+        CocosKeelingIslands = "CC",
+        /// Colombia
+        Colombia = "CO",
+        /// Comoros
+        Comoros = "KM",
+        /// Congo Republic of the Democratic
+        CongoRepublicOfTheDemocratic = "CG",
+        /// Congo-Brazzaville
+        CongoBrazzaville = "CD",
+        /// Cook Islands
+        CookIslands = "CK",
+        /// Costa Rica
+        CostaRica = "CR",
+        /// Croatia (Hrvatska)
+        Croatia = "HR",
+        /// Cuba
+        Cuba = "CU",
+        /// Curaçao
+        Curaçao = "CW",
+        /// Cyprus
+        Cyprus = "CY",
+        /// Cyprus (pre-Euro)
+        CyprusPreEuro = "XCY",
+        /// Czech Republic
+        CzechRepublic = "CZ",
+        /// Denmark
+        Denmark = "DK",
+        /// Djibouti
+        Djibouti = "DJ",
+        /// Dominica
+        Dominica = "DM",
+        /// Dominican Republic
+        DominicanRepublic = "DO",
+        /// East Timor
+        EastTimorP = "TP",
+        /// East Timor
+        EastTimorL = "TL",
+        /// Ecuador
+        Ecuador = "EC",
+        /// Egypt
+        Egypt = "EG",
+        /// El Salvador
+        ElSalvador = "SV",
+        /// Equatorial Guinea
+        EquatorialGuinea = "GQ",
+        /// Eritrea
+        Eritrea = "ER",
+        /// Estonia
+        Estonia = "EE",
+        /// Estonia (pre-Euro)
+        EstoniaPreEuro = "XEE",
+        /// Ethiopia
+        Ethiopia = "ET",
+        /// Europe
+        Europe = "XS",
+        /// Falkland Islands (Malvinas)
+        FalklandIslands = "FK",
+        /// Faroe Islands
+        FaroeIslands = "FO",
+        /// Fiji
+        Fiji = "FJ",
+        /// Finland
+        Finland = "FI",
+        /// France
+        France = "FR",
+        /// French Guiana
+        FrenchGuiana = "GF",
+        /// French Polynesia
+        FrenchPolynesia = "PF",
+        /// French Southern Territories
+        FrenchSouthernTerritories = "TF",
+        /// Gabon
+        Gabon = "GA",
+        /// Gambia
+        Gambia = "GM",
+        /// Georgia
+        Georgia = "GE",
+        /// Germany
+        Germany = "DE",
+        /// Ghana
+        Ghana = "GH",
+        /// Gibraltar
+        Gibraltar = "GI",
+        /// Greece
+        Greece = "GR",
+        /// Greenland
+        Greenland = "GL",
+        /// Grenada
+        Grenada = "GD",
+        /// Guadeloupe
+        Guadeloupe = "GP",
+        /// Guam
+        Guam = "GU",
+        /// Guatemala
+        Guatemala = "GT",
+        /// Guernsey
+        Guernsey = "GG",
+        /// Guinea
+        Guinea = "GN",
+        /// Guinea-Bissau
+        GuineaBissau = "GW",
+        /// Guyana
+        Guyana = "GY",
+        /// Haiti
+        Haiti = "HT",
+        /// Heard and Mc Donald Islands
+        HeardAndMcDonaldIslands = "HM",
+        /// Honduras
+        Honduras = "HN",
+        /// Hong Kong
+        HongKong = "HK",
+        /// Hungary
+        Hungary = "HU",
+        /// Iceland
+        Iceland = "IS",
+        /// India
+        India = "IN",
+        /// Indonesia
+        Indonesia = "ID",
+        /// International
+        International = "_IN",
+        /// Iran (Islamic Republic of)
+        Iran = "IR",
+        /// Iraq
+        Iraq = "IQ",
+        /// Ireland
+        Ireland = "IE",
+        /// Isle of Man
+        IsleOfMan = "IM",
+        /// Israel
+        Israel = "IL",
+        /// Italy
+        Italy = "IT",
+        /// Ivory Coast
+        IvoryCoast = "CI",
+        /// Jamaica
+        Jamaica = "JM",
+        /// Japan
+        Japan = "JP",
+        /// Jersey
+        Jersey = "JE",
+        /// Jordan
+        Jordan = "JO",
+        /// Kazakhstan
+        Kazakhstan = "KZ",
+        /// Kenya
+        Kenya = "KE",
+        /// Kiribati
+        Kiribati = "KI",
+        /// Kuwait
+        Kuwait = "KW",
+        /// Kyrgyzstan
+        Kyrgyzstan = "KG",
+        /// Lao Peoples Democratic Republic
+        LaoPeoplesDemocraticRepublic = "LA",
+        /// Latin America
+        LatinAmerica = "_LA",
+        /// Latvia
+        Latvia = "LV",
+        /// Latvia (pre-Euro)
+        LatviaPreEuro = "XLV",
+        /// Lebanon
+        Lebanon = "LB",
+        /// Lesotho
+        Lesotho = "LS",
+        /// Liberia
+        Liberia = "LR",
+        /// Libyan Arab Jamahiriya
+        LibyanArabJamahiriya = "LY",
+        /// Liechtenstein
+        Liechtenstein = "LI",
+        /// Lithuania
+        Lithuania = "LT",
+        /// Lithuania (pre-Euro)
+        LithuaniaPreEuro = "XLT",
+        /// Luxembourg
+        Luxembourg = "LU",
+        /// Macau
+        Macau = "MO",
+        /// Madagascar
+        Madagascar = "MG",
+        /// Malawi
+        Malawi = "MW",
+        /// Malaysia
+        Malaysia = "MY",
+        /// Maldives
+        Maldives = "MV",
+        /// Mali
+        Mali = "ML",
+        /// Malta
+        Malta = "MT",
+        /// Malta (pre-Euro)
+        MaltaPreEuro = "XMT",
+        /// Marshall Islands
+        MarshallIslands = "MH",
+        /// Martinique
+        Martinique = "MQ",
+        /// Mauritania
+        Mauritania = "MR",
+        /// Mauritius
+        Mauritius = "MU",
+        /// Mayotte
+        Mayotte = "YT",
+        /// Mexico
+        Mexico = "MX",
+        /// Micronesia (Federated States of)
+        Micronesia = "FM",
+        /// Middle East
+        MiddleEast = "_ME",
+        /// Moldova (Republic of)
+        MoldovaRepublicOf = "MD",
+        /// Monaco
+        Monaco = "MC",
+        /// Mongolia
+        Mongolia = "MN",
+        /// Montenegro
+        Montenegro = "ME",
+        /// Montserrat
+        Montserrat = "MS",
+        /// Morocco
+        Morocco = "MA",
+        /// Mozambique
+        Mozambique = "MZ",
+        /// Myanmar
+        Myanmar = "MM",
+        /// Namibia
+        Namibia = "NA",
+        /// Nauru
+        Nauru = "NR",
+        /// Nepal
+        Nepal = "NP",
+        /// Netherlands
+        Netherlands = "NL",
+        /// Netherlands Antilles
+        NetherlandsAntilles = "AN",
+        /// New Caledonia
+        NewCaledonia = "NC",
+        /// New Zealand
+        NewZealand = "NZ",
+        /// Nicaragua
+        Nicaragua = "NI",
+        /// Niger
+        Niger = "NE",
+        /// Nigeria
+        Nigeria = "NG",
+        /// Niue
+        Niue = "NU",
+        /// Norfolk Island
+        NorfolkIsland = "NF",
+        /// North Korea
+        NorthKorea = "KP",
+        /// North Macedonia
+        NorthMacedonia = "MK",
+        /// Northern Mariana Islands
+        NorthernMarianaIslands = "MP",
+        /// Norway
+        Norway = "NO",
+        /// Oman
+        Oman = "OM",
+        /// Pakistan
+        Pakistan = "PK",
+        /// Palau
+        Palau = "PW",
+        /// Panama
+        Panama = "PA",
+        /// Papua New Guinea
+        PapuaNewGuinea = "PG",
+        /// Paraguay
+        Paraguay = "PY",
+        /// Peru
+        Peru = "PE",
+        /// Philippines
+        Philippines = "PH",
+        /// Pitcairn
+        Pitcairn = "PN",
+        /// Poland
+        Poland = "PL",
+        /// Portugal
+        Portugal = "PT",
+        /// Puerto Rico
+        PuertoRico = "PR",
+        /// Qatar
+        Qatar = "QA",
+        /// Reunion
+        Reunion = "RE",
+        /// Romania
+        Romania = "RO",
+        /// Russian Federation
+        RussianFederation = "RU",
+        /// Rwanda
+        Rwanda = "RW",
+        /// Saint Barthélemy
+        SaintBarthélemy = "BL",
+        /// Saint Helena, Ascension and Tristan da Cunha
+        SaintHelena = "SH",
+        /// Saint Kitts
+        SaintKitts = "KN",
+        /// Saint Lucia
+        SaintLucia = "LC",
+        /// Saint Martin
+        SaintMartin = "MF",
+        /// Saint Pierre and Miquelon
+        SaintPierreAndMiquelon = "PM",
+        /// Saint Vincent Grenadines
+        SaintVincentGrenadines = "VC",
+        /// Samoa
+        Samoa = "WS",
+        /// San Marino
+        SanMarino = "SM",
+        /// Sao Tome and Principe
+        SaoTomeAndPrincipe = "ST",
+        /// Saudi Arabia
+        SaudiArabia = "SA",
+        /// Senegal
+        Senegal = "SN",
+        /// Serbia
+        Serbia = "RS",
+        /// Seychelles
+        Seychelles = "SC",
+        /// Sierra Leone
+        SierraLeone = "SL",
+        /// Singapore
+        Singapore = "SG",
+        /// Sint Maarten
+        SintMaarten = "SX",
+        /// Slovakia (Slovak Republic)
+        Slovakia = "SK",
+        /// Slovakia (pre-Euro)
+        SlovakiaPreEuro = "XSK",
+        /// Slovenia
+        Slovenia = "SI",
+        /// Solomon Islands
+        SolomonIslands = "SB",
+        /// Somalia
+        Somalia = "SO",
+        /// South Africa
+        SouthAfrica = "ZA",
+        /// South Georgia and the South Sandwich Islands
+        SouthGeorgiaAndTheSouthSandwichIslands = "GS",
+        /// South Korea
+        SouthKorea = "KR",
+        /// South Sudan
+        SouthSudan = "SS",
+        /// Spain
+        Spain = "ES",
+        /// Sri Lanka
+        SriLanka = "LK",
+        /// State of Palestine
+        StateOfPalestine = "PS",
+        /// Sudan
+        Sudan = "SD",
+        /// Suriname
+        Suriname = "SR",
+        /// Svalbard and Jan Mayen Islands
+        SvalbardAndJanMayenIslands = "SJ",
+        /// Swaziland
+        Swaziland = "SZ",
+        /// Sweden
+        Sweden = "SE",
+        /// Switzerland
+        Switzerland = "CH",
+        /// Syrian Arab Republic
+        SyrianArabRepublic = "SY",
+        /// Taiwan
+        Taiwan = "TW",
+        /// Tajikistan
+        Tajikistan = "TJ",
+        /// Tanzania
+        Tanzania = "TZ",
+        /// Thailand
+        Thailand = "TH",
+        /// Togo
+        Togo = "TG",
+        /// Tokelau
+        Tokelau = "TK",
+        /// Tonga
+        Tonga = "TO",
+        /// Trinidad and Tobago
+        TrinidadAndTobago = "TT",
+        /// Tunisia
+        Tunisia = "TN",
+        /// Turkey
+        Turkey = "TR",
+        /// Turkmenistan
+        Turkmenistan = "TM",
+        /// Turks and Caicos Islands
+        TurksAndCaicosIslands = "TC",
+        /// Tuvalu
+        Tuvalu = "TV",
+        /// Uganda
+        Uganda = "UG",
+        /// Ukraine
+        Ukraine = "UA",
+        /// United Arab Emirates
+        UnitedArabEmirates = "AE",
+        /// United Kingdom
+        UnitedKingdom = "GB",
+        /// United States
+        UnitedStates = "US",
+        /// United States Minor Outlying Islands
+        UnitedStatesMinorOutlyingIslands = "UM",
+        /// Unknown
+        Unknown = "UN",
+        /// Uruguay
+        Uruguay = "UY",
+        /// Uzbekistan
+        Uzbekistan = "UZ",
+        /// Vanuatu
+        Vanuatu = "VU",
+        /// Vatican City State (Holy See)
+        VaticanCityState = "VA",
+        /// Venezuela
+        Venezuela = "VE",
+        /// Vietnam
+        Vietnam = "VN",
+        /// Virgin Islands (British)
+        VirginIslandsBritish = "VG",
+        /// Virgin Islands (US)
+        VirginIslandsUS = "VI",
+        /// Wallis and Futuna Islands
+        WallisAndFutunaIslands = "WF",
+        /// Western Sahara
+        WesternSahara = "EH",
+        /// Yemen
+        Yemen = "YE",
+        /// Zambia
+        Zambia = "ZM",
+        /// Zimbabwe
+        Zimbabwe = "ZW",
+        /// Åland Islands
+        ÅlandIslands = "AX",
+    }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
-pub enum SaleAllocationMethod {
-    /// Average Cost
-    #[serde(rename = "average")]
-    AverageCost,
-    /// Portfolio Default
-    #[serde(rename = "default")]
-    PortfolioDefault,
-    /// First In, First Out
-    #[serde(rename = "fifo")]
-    FirstInFirstOut,
-    /// Last In, First Out
-    #[serde(rename = "lifo")]
-    LastInFirstOut,
-    /// Maximise Gain
-    #[serde(rename = "maximise_cr")]
-    MaximiseGain,
-    /// Minimise Gain
-    #[serde(rename = "minimise_cr")]
-    MinimiseGain,
-    /// Minimise CGT
-    #[serde(rename = "ss_minimise")]
-    MinimiseCgt,
+string_enum! {
+    #[allow(clippy::upper_case_acronyms)]
+    pub enum Currency {
+        /// Afghani
+        AFN = "AFN",
+        /// Albanian Lek
+        ALL = "ALL",
+        /// Algerian Dinar
+        DZD = "DZD",
+        /// Argentine Peso
+        ARS = "ARS",
+        /// Armenian Dram
+        AMD = "AMD",
+        /// Australian Dollars
+        AUD = "AUD",
+        /// Azerbaijani Manat
+        AZN = "AZN",
+        /// Bahamian Dollar
+        BSD = "BSD",
+        /// Bahraini Dinar
+        BHD = "BHD",
+        /// Baht
+        THB = "THB",
+        /// Barbadian Dollar
+        BBD = "BBD",
+        /// Belarusian Ruble
+        BYN = "BYN",
+        /// Belizean Dollar
+        BZD = "BZD",
+        /// Bermudian Dollar
+        BMD = "BMD",
+        /// Bitcoin
+        BTC = "BTC" | "XBT",
+        /// Bitshares
+        BTS = "BTS",
+        /// Bolivar
+        VEF = "VEF",
+        /// Boliviano
+        BOB = "BOB",
+        /// Bosnia and Herzegovina Convertible Mark
+        BAM = "BAM",
+        /// Brazil Real
+        BRL = "BRL",
+        /// Brunei Dollar
+        BND = "BND",
+        /// Burundi Franc
+        BIF = "BIF",
+        /// CFA Franc BCEAO
+        XOF = "XOF",
+        /// CFA Franc BEAC
+        XAF = "XAF",
+        /// CFP Franc
+        XPF = "XPF",
+        /// Canadian Dollar
+        CAD = "CAD",
+        /// Caymanian Dollar
+        KYD = "KYD",
+        /// Chilean Peso
+        CLP = "CLP",
+        /// Chinese Yuan (Offshore)
+        CNH = "CNH",
+        /// Colombian Peso
+        COP = "COP",
+        /// Comoran Franc
+        KMF = "KMF",
+        /// Congolese Frank
+        CDF = "CDF",
+        /// Cordoba Oro
+        NIO = "NIO",
+        /// Costa Rican Colon
+        CRC = "CRC",
+        /// Croatian Kuna
+        HRK = "HRK",
+        /// Cuban Peso
+        CUP = "CUP",
+        /// Cypriot Pound
+        CYP = "CYP",
+        /// Dalasi
+        GMD = "GMD",
+        /// Danish Krone
+        DKK = "DKK",
+        /// Dash
+        DAS = "DAS",
+        /// Dinar
+        SDG = "SDG",
+        /// Dirham
+        MAD = "MAD",
+        /// Dirham
+        AED = "AED",
+        /// Djiboutian Franc
+        DJF = "DJF",
+        /// Dobra
+        STD = "STD",
+        /// Dominican Peso
+        DOP = "DOP",
+        /// Dong
+        VND = "VND",
+        /// East Caribbean Dollar
+        XCD = "XCD",
+        /// Egyptian Pound
+        EGP = "EGP",
+        /// Escudo
+        CVE = "CVE",
+        /// Estonian Kroon
+        EEK = "EEK",
+        /// Ethereum
+        ETH = "ETH",
+        /// Ethiopian Birr
+        ETB = "ETB",
+        /// Euros
+        EUR = "EUR",
+        /// Falkland Pound
+        FKP = "FKP",
+        /// Fijian Dollar
+        FJD = "FJD",
+        /// Forint
+        HUF = "HUF",
+        /// Gibraltar Pound
+        GIP = "GIP",
+        /// Gourde
+        HTG = "HTG",
+        /// Guinean Franc
+        GNF = "GNF",
+        /// Guyanaese Dollar
+        GYD = "GYD",
+        /// Hong Kong Dollar
+        HKD = "HKD",
+        /// Hryvnia
+        UAH = "UAH",
+        /// Icelandic Krona
+        ISK = "ISK",
+        /// Indian Rupee
+        INR = "INR",
+        /// Indonesian Rupiah
+        IDR = "IDR",
+        /// Iranian Rial
+        IRR = "IRR",
+        /// Iraqi Dinar
+        IQD = "IQD",
+        /// Jamaican Dollar
+        JMD = "JMD",
+        /// Japanese Yen
+        JPY = "JPY",
+        /// Jordanian Dinar
+        JOD = "JOD",
+        /// Kazahstani Tenge
+        KZT = "KZT",
+        /// Kenyan Shilling
+        KES = "KES",
+        /// Kina
+        PGK = "PGK",
+        /// Kip
+        LAK = "LAK",
+        /// Koruna
+        CZK = "CZK",
+        /// Koruna
+        SKK = "SKK",
+        /// Krona
+        SEK = "SEK",
+        /// Kuwaiti Dinar
+        KWD = "KWD",
+        /// Kwacha
+        ZMK = "ZMK",
+        /// Kyat
+        MMK = "MMK",
+        /// Lari
+        GEL = "GEL",
+        /// Lat
+        LVL = "LVL",
+        /// Lebanese Pound
+        LBP = "LBP",
+        /// Lempira
+        HNL = "HNL",
+        /// Leone
+        SLL = "SLL",
+        /// Leu
+        MDL = "MDL",
+        /// Leu
+        RON = "RON",
+        /// Lev
+        BGN = "BGN",
+        /// Liberian Dollar
+        LRD = "LRD",
+        /// Libyan Dinar
+        LYD = "LYD",
+        /// Lilangeni
+        SZL = "SZL",
+        /// Lira
+        TRY = "TRY",
+        /// Lita
+        LTL = "LTL",
+        /// Litecoin
+        LTC = "LTC",
+        /// Loti
+        LSL = "LSL",
+        /// Macanese Pataca
+        MOP = "MOP",
+        /// Macedonian Denar
+        MKD = "MKD",
+        /// Malagasy Franc
+        MGA = "MGA",
+        /// Malawian Kwacha
+        MWK = "MWK",
+        /// Maldivian Rufiyaa
+        MVR = "MVR",
+        /// Maltese Lira
+        MTL = "MTL",
+        /// Manat
+        TMT = "TMT",
+        /// Mauritian Rupee
+        MUR = "MUR",
+        /// Metical
+        MZN = "MZN",
+        /// Mexican Peso
+        MXN = "MXN",
+        /// Monero
+        XMR = "XMR",
+        /// Naira
+        NGN = "NGN",
+        /// Namibian Dollar
+        NAD = "NAD",
+        /// Nepalese Rupee
+        NPR = "NPR",
+        /// Netherlands Antilles Guilder
+        ANG = "ANG",
+        /// New Taiwan Dollar
+        TWD = "TWD",
+        /// New Zealand Dollars
+        NZD = "NZD",
+        /// North Korean Won
+        KPW = "KPW",
+        /// Norwegian Krone
+        NOK = "NOK",
+        /// Nuevo Sol
+        PEN = "PEN",
+        /// Omani Rial
+        OMR = "OMR",
+        /// Ouguiya
+        MRO = "MRO",
+        /// Pakistani Rupee
+        PKR = "PKR",
+        /// Palanga
+        TOP = "TOP",
+        /// Panamaian Balboa
+        PAB = "PAB",
+        /// Paraguayan Guaraní
+        PYG = "PYG",
+        /// Philippine Peso
+        PHP = "PHP",
+        /// Pounds Sterling
+        GBP = "GBP",
+        /// Pula
+        BWP = "BWP",
+        /// Qatari Riyal
+        QAR = "QAR",
+        /// Quetzal
+        GTQ = "GTQ",
+        /// Rand
+        ZAR = "ZAR",
+        /// Rial
+        YER = "YER",
+        /// Riel
+        KHR = "KHR",
+        /// Ringgit
+        MYR = "MYR",
+        /// Ripple
+        XRP = "XRP",
+        /// Riyal
+        SAR = "SAR",
+        /// Ruble
+        RUB = "RUB",
+        /// Rwanda Franc
+        RWF = "RWF",
+        /// Salvadoran Colon
+        SVC = "SVC",
+        /// Serbian Dinar
+        RSD = "RSD",
+        /// Seychellois Rupee
+        SCR = "SCR",
+        /// Shekel
+        ILS = "ILS",
+        /// Shilling
+        SOS = "SOS",
+        /// Shilling
+        TZS = "TZS",
+        /// Shilling
+        UGX = "UGX",
+        /// Singapore Dollar
+        SGD = "SGD",
+        /// Solomon Islands Dollar
+        SBD = "SBD",
+        /// Som
+        KGS = "KGS",
+        /// South Korean Won
+        KRW = "KRW",
+        /// Sri Lankan Rupee
+        LKR = "LKR",
+        /// Stellar
+        XLM = "XLM",
+        /// Sucre
+        ECS = "ECS",
+        /// Surinamese Guilder
+        SRD = "SRD",
+        /// Swiss Franc
+        CHF = "CHF",
+        /// Syrian Pound
+        SYP = "SYP",
+        /// Tajikistan Ruble
+        TJS = "TJS",
+        /// Taka
+        BDT = "BDT",
+        /// Trinidad and Tobago Dollar
+        TTD = "TTD",
+        /// Tugrik
+        MNT = "MNT",
+        /// Tunisian Dinar
+        TND = "TND",
+        /// United States Dollar
+        USD = "USD",
+        /// Uruguayan Peso
+        UYU = "UYU",
+        /// Uzbekistani Soʻm
+        UZS = "UZS",
+        /// Vanuatu Vatu
+        VUV = "VUV",
+        /// Yuan Renminbi
+        CNY = "CNY",
+        /// Zimbabwe Dollar
+        ZWD = "ZWD",
+        /// Zloty
+        PLN = "PLN",
+        /// Tether (USDT)
+        USDT = "USDT",
+    }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum CashAccountTransactionTypeName {
-    #[serde(rename = "OPENING BALANCE")]
-    OpeningBalance,
-    Deposit,
-    Withdrawal,
-    InterestPayment,
-    Fee,
-    FeeReimbursement,
+impl Currency {
+    /// The number of digits after the decimal point, as defined by ISO 4217.
+    ///
+    /// Returns `None` for cryptocurrencies and codes this crate doesn't know.
+    pub fn minor_units(&self) -> Option<u32> {
+        use Currency::*;
+
+        match self {
+            BIF | CLP | DJF | GNF | ISK | JPY | KMF | KRW | PYG | RWF | UGX | VND | VUV | XAF
+            | XOF | XPF => Some(0),
+            BHD | IQD | JOD | KWD | LYD | OMR | TND => Some(3),
+            BTC | BTS | DAS | ETH | LTC | USDT | XLM | XMR | XRP | Other(_) => None,
+            _ => Some(2),
+        }
+    }
+
+    /// The symbol commonly used for amounts in this currency, e.g. `$` or `€`.
+    ///
+    /// Returns `None` for currencies without a widely used symbol.
+    pub fn symbol(&self) -> Option<&'static str> {
+        use Currency::*;
+
+        let symbol = match self {
+            ARS | AUD | BBD | BMD | BND | BSD | BZD | CAD | CLP | COP | CUP | DOP | FJD | GYD
+            | HKD | JMD | KYD | LRD | MXN | NAD | NZD | SBD | SGD | SRD | TTD | USD | UYU | XCD => {
+                "$"
+            }
+            EUR => "€",
+            GBP | EGP | FKP | GIP | LBP | SYP => "£",
+            JPY | CNY | CNH => "¥",
+            INR => "₹",
+            KRW | KPW => "₩",
+            ILS => "₪",
+            THB => "฿",
+            PHP => "₱",
+            VND => "₫",
+            RUB => "₽",
+            TRY => "₺",
+            UAH => "₴",
+            NGN => "₦",
+            KZT => "₸",
+            MNT => "₮",
+            PYG => "₲",
+            CRC => "₡",
+            GEL => "₾",
+            AZN => "₼",
+            AFN => "؋",
+            LAK => "₭",
+            BTC => "₿",
+            ETH => "Ξ",
+            BRL => "R$",
+            TWD => "NT$",
+            ZAR => "R",
+            IDR => "Rp",
+            MYR => "RM",
+            CHF => "CHF",
+            DKK | ISK | NOK | SEK => "kr",
+            PLN => "zł",
+            CZK => "Kč",
+            HUF => "Ft",
+            RON => "lei",
+            BGN => "лв",
+            LKR | NPR | PKR | MUR | SCR => "Rs",
+            PEN => "S/",
+            _ => return None,
+        };
+
+        Some(symbol)
+    }
+}
+
+impl Market {
+    /// The country the market is in. Returns `None` for markets that aren't tied to a
+    /// country, such as currencies, crypto or metals.
+    pub fn country(&self) -> Option<Country> {
+        self.details().map(|(country, _, _)| country)
+    }
+
+    /// The currency trades on the market are settled in.
+    pub fn currency(&self) -> Option<Currency> {
+        self.details().map(|(_, currency, _)| currency)
+    }
+
+    /// The IANA timezone of the market, e.g. `Pacific/Auckland`.
+    pub fn timezone(&self) -> Option<&'static str> {
+        self.details().map(|(_, _, timezone)| timezone)
+    }
+
+    /// Whether holdings in the market can have fractional quantities.
+    ///
+    /// Funds, currencies, crypto and metals are held in fractional units, as are US
+    /// listed shares, which brokers commonly trade and reinvest in fractions.
+    pub fn allows_fractional_quantities(&self) -> bool {
+        use Market::*;
+
+        matches!(
+            self,
+            FundAU
+                | FundNZ
+                | MFund
+                | FundCA
+                | FundUK
+                | FundUS
+                | FundIE
+                | FundLU
+                | FX
+                | CRYPTO
+                | METAL
+                | GOLDIE
+                | OTHER
+                | NYSE
+                | NASDAQ
+                | AMEX
+                | BATS
+                | MFQS
+                | OTC
+                | OTCBB
+                | Other(_)
+        )
+    }
+
+    fn details(&self) -> Option<(Country, Currency, &'static str)> {
+        use Market::*;
+
+        let details = match self {
+            NZX | FundNZ | GOLDIE => (Country::NewZealand, Currency::NZD, "Pacific/Auckland"),
+            ASX | FundAU | MFund | CXA => (Country::Australia, Currency::AUD, "Australia/Sydney"),
+            NYSE | NASDAQ | AMEX | BATS | MFQS | OTC | OTCBB | FundUS => {
+                (Country::UnitedStates, Currency::USD, "America/New_York")
+            }
+            LSE | FundUK => (Country::UnitedKingdom, Currency::GBP, "Europe/London"),
+            EURONEXT => (Country::Europe, Currency::EUR, "Europe/Paris"),
+            CNSX | TSE | CVE | NEO | FundCA => (Country::Canada, Currency::CAD, "America/Toronto"),
+            HKG => (Country::HongKong, Currency::HKD, "Asia/Hong_Kong"),
+            SGX => (Country::Singapore, Currency::SGD, "Asia/Singapore"),
+            JSE => (Country::SouthAfrica, Currency::ZAR, "Africa/Johannesburg"),
+            FRA | XETR => (Country::Germany, Currency::EUR, "Europe/Berlin"),
+            SWX => (Country::Switzerland, Currency::CHF, "Europe/Zurich"),
+            TYO => (Country::Japan, Currency::JPY, "Asia/Tokyo"),
+            BIT => (Country::Italy, Currency::EUR, "Europe/Rome"),
+            BME => (Country::Spain, Currency::EUR, "Europe/Madrid"),
+            BSE | NSE => (Country::India, Currency::INR, "Asia/Kolkata"),
+            SHE | SHG => (Country::China, Currency::CNY, "Asia/Shanghai"),
+            KRX | KOSDAQ => (Country::SouthKorea, Currency::KRW, "Asia/Seoul"),
+            TAI => (Country::Taiwan, Currency::TWD, "Asia/Taipei"),
+            STO => (Country::Sweden, Currency::SEK, "Europe/Stockholm"),
+            CSE => (Country::Denmark, Currency::DKK, "Europe/Copenhagen"),
+            ICE => (Country::Iceland, Currency::ISK, "Atlantic/Reykjavik"),
+            HEL => (Country::Finland, Currency::EUR, "Europe/Helsinki"),
+            OSL => (Country::Norway, Currency::NOK, "Europe/Oslo"),
+            KLS => (Country::Malaysia, Currency::MYR, "Asia/Kuala_Lumpur"),
+            DUB | FundIE => (Country::Ireland, Currency::EUR, "Europe/Dublin"),
+            MISX => (Country::RussianFederation, Currency::RUB, "Europe/Moscow"),
+            BKK => (Country::Thailand, Currency::THB, "Asia/Bangkok"),
+            WAR => (Country::Poland, Currency::PLN, "Europe/Warsaw"),
+            TLV => (Country::Israel, Currency::ILS, "Asia/Jerusalem"),
+            BDP => (Country::Hungary, Currency::HUF, "Europe/Budapest"),
+            PSE => (Country::CzechRepublic, Currency::CZK, "Europe/Prague"),
+            ASE => (Country::Greece, Currency::EUR, "Europe/Athens"),
+            BVMF => (Country::Brazil, Currency::BRL, "America/Sao_Paulo"),
+            BMV => (Country::Mexico, Currency::MXN, "America/Mexico_City"),
+            BVL => (Country::Peru, Currency::PEN, "America/Lima"),
+            IDX => (Country::Indonesia, Currency::IDR, "Asia/Jakarta"),
+            BVB => (Country::Romania, Currency::RON, "Europe/Bucharest"),
+            VIE => (Country::Austria, Currency::EUR, "Europe/Vienna"),
+            FundLU => (Country::Luxembourg, Currency::EUR, "Europe/Luxembourg"),
+            PHS => (Country::Philippines, Currency::PHP, "Asia/Manila"),
+            BIST => (Country::Turkey, Currency::TRY, "Europe/Istanbul"),
+            OTHER | FX | CRYPTO | METAL | Other(_) => return None,
+        };
+
+        Some(details)
+    }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+impl Country {
+    /// The currency used in the country. Returns `None` for regions and for countries
+    /// whose currency isn't one of the [`Currency`] codes.
+    pub fn currency(&self) -> Option<Currency> {
+        let currency = match self {
+            Country::Afghanistan => Currency::AFN,
+            Country::Albania => Currency::ALL,
+            Country::Algeria => Currency::DZD,
+            Country::AmericanSamoa
+            | Country::BonaireSintEustatiusAndSaba
+            | Country::BritishIndianOceanTerritory
+            | Country::EastTimorP
+            | Country::EastTimorL
+            | Country::Ecuador
+            | Country::ElSalvador
+            | Country::Guam
+            | Country::MarshallIslands
+            | Country::Micronesia
+            | Country::NorthernMarianaIslands
+            | Country::Palau
+            | Country::PuertoRico
+            | Country::TurksAndCaicosIslands
+            | Country::UnitedStates
+            | Country::UnitedStatesMinorOutlyingIslands
+            | Country::VirginIslandsBritish
+            | Country::VirginIslandsUS => Currency::USD,
+            Country::Andorra
+            | Country::Austria
+            | Country::Belgium
+            | Country::Croatia
+            | Country::Cyprus
+            | Country::Estonia
+            | Country::Europe
+            | Country::Finland
+            | Country::France
+            | Country::FrenchGuiana
+            | Country::FrenchSouthernTerritories
+            | Country::Germany
+            | Country::Greece
+            | Country::Guadeloupe
+            | Country::Ireland
+            | Country::Italy
+            | Country::Latvia
+            | Country::Lithuania
+            | Country::Luxembourg
+            | Country::Malta
+            | Country::Martinique
+            | Country::Mayotte
+            | Country::Monaco
+            | Country::Montenegro
+            | Country::Netherlands
+            | Country::Portugal
+            | Country::Reunion
+            | Country::SaintBarthélemy
+            | Country::SaintMartin
+            | Country::SaintPierreAndMiquelon
+            | Country::SanMarino
+            | Country::Slovakia
+            | Country::Slovenia
+            | Country::Spain
+            | Country::VaticanCityState
+            | Country::ÅlandIslands => Currency::EUR,
+            Country::Anguilla
+            | Country::AntiguaAndBarbuda
+            | Country::Dominica
+            | Country::Grenada
+            | Country::Montserrat
+            | Country::SaintKitts
+            | Country::SaintLucia
+            | Country::SaintVincentGrenadines => Currency::XCD,
+            Country::Argentina => Currency::ARS,
+            Country::Armenia => Currency::AMD,
+            Country::Australia
+            | Country::ChristmasIsland
+            | Country::CocosKeelingIslands
+            | Country::HeardAndMcDonaldIslands
+            | Country::Kiribati
+            | Country::Nauru
+            | Country::NorfolkIsland
+            | Country::Tuvalu => Currency::AUD,
+            Country::Azerbaijan => Currency::AZN,
+            Country::Bahamas => Currency::BSD,
+            Country::Bahrain => Currency::BHD,
+            Country::Bangladesh => Currency::BDT,
+            Country::Barbados => Currency::BBD,
+            Country::Belarus => Currency::BYN,
+            Country::Belize => Currency::BZD,
+            Country::Benin
+            | Country::BurkinaFaso
+            | Country::GuineaBissau
+            | Country::IvoryCoast
+            | Country::Mali
+            | Country::Niger
+            | Country::Senegal
+            | Country::Togo => Currency::XOF,
+            Country::Bermuda => Currency::BMD,
+            Country::Bitcoin => Currency::BTC,
+            Country::Bolivia => Currency::BOB,
+            Country::BosniaAndHerzegovina => Currency::BAM,
+            Country::Botswana => Currency::BWP,
+            Country::BouvetIsland | Country::Norway | Country::SvalbardAndJanMayenIslands => {
+                Currency::NOK
+            }
+            Country::Brazil => Currency::BRL,
+            Country::Brunei => Currency::BND,
+            Country::Bulgaria => Currency::BGN,
+            Country::Burundi => Currency::BIF,
+            Country::Cambodia => Currency::KHR,
+            Country::Cameroon
+            | Country::CentralAfricanRepublic
+            | Country::Chad
+            | Country::CongoRepublicOfTheDemocratic
+            | Country::EquatorialGuinea
+            | Country::Gabon => Currency::XAF,
+            Country::Canada => Currency::CAD,
+            Country::CapeVerde => Currency::CVE,
+            Country::CaymanIslands => Currency::KYD,
+            Country::Chile => Currency::CLP,
+            Country::China => Currency::CNY,
+            Country::ChinaOffshore => Currency::CNH,
+            Country::Colombia => Currency::COP,
+            Country::Comoros => Currency::KMF,
+            Country::CongoBrazzaville => Currency::CDF,
+            Country::CookIslands
+            | Country::NewZealand
+            | Country::Niue
+            | Country::Pitcairn
+            | Country::Tokelau => Currency::NZD,
+            Country::CostaRica => Currency::CRC,
+            Country::Cuba => Currency::CUP,
+            Country::Curaçao | Country::NetherlandsAntilles | Country::SintMaarten => {
+                Currency::ANG
+            }
+            Country::CyprusPreEuro => Currency::CYP,
+            Country::CzechRepublic => Currency::CZK,
+            Country::Denmark | Country::FaroeIslands | Country::Greenland => Currency::DKK,
+            Country::Djibouti => Currency::DJF,
+            Country::DominicanRepublic => Currency::DOP,
+            Country::Egypt => Currency::EGP,
+            Country::EstoniaPreEuro => Currency::EEK,
+            Country::Ethiopia => Currency::ETB,
+            Country::FalklandIslands => Currency::FKP,
+            Country::Fiji => Currency::FJD,
+            Country::FrenchPolynesia | Country::NewCaledonia | Country::WallisAndFutunaIslands => {
+                Currency::XPF
+            }
+            Country::Gambia => Currency::GMD,
+            Country::Georgia => Currency::GEL,
+            Country::Gibraltar => Currency::GIP,
+            Country::Guatemala => Currency::GTQ,
+            Country::Guernsey
+            | Country::IsleOfMan
+            | Country::Jersey
+            | Country::SouthGeorgiaAndTheSouthSandwichIslands
+            | Country::UnitedKingdom => Currency::GBP,
+            Country::Guinea => Currency::GNF,
+            Country::Guyana => Currency::GYD,
+            Country::Haiti => Currency::HTG,
+            Country::Honduras => Currency::HNL,
+            Country::HongKong => Currency::HKD,
+            Country::Hungary => Currency::HUF,
+            Country::Iceland => Currency::ISK,
+            Country::India => Currency::INR,
+            Country::Indonesia => Currency::IDR,
+            Country::Iran => Currency::IRR,
+            Country::Iraq => Currency::IQD,
+            Country::Israel | Country::StateOfPalestine => Currency::ILS,
+            Country::Jamaica => Currency::JMD,
+            Country::Japan => Currency::JPY,
+            Country::Jordan => Currency::JOD,
+            Country::Kazakhstan => Currency::KZT,
+            Country::Kenya => Currency::KES,
+            Country::Kuwait => Currency::KWD,
+            Country::Kyrgyzstan => Currency::KGS,
+            Country::LaoPeoplesDemocraticRepublic => Currency::LAK,
+            Country::LatviaPreEuro => Currency::LVL,
+            Country::Lebanon => Currency::LBP,
+            Country::Lesotho => Currency::LSL,
+            Country::Liberia => Currency::LRD,
+            Country::LibyanArabJamahiriya => Currency::LYD,
+            Country::Liechtenstein | Country::Switzerland => Currency::CHF,
+            Country::LithuaniaPreEuro => Currency::LTL,
+            Country::Macau => Currency::MOP,
+            Country::Madagascar => Currency::MGA,
+            Country::Malawi => Currency::MWK,
+            Country::Malaysia => Currency::MYR,
+            Country::Maldives => Currency::MVR,
+            Country::MaltaPreEuro => Currency::MTL,
+            Country::Mauritania => Currency::MRO,
+            Country::Mauritius => Currency::MUR,
+            Country::Mexico => Currency::MXN,
+            Country::MoldovaRepublicOf => Currency::MDL,
+            Country::Mongolia => Currency::MNT,
+            Country::Morocco | Country::WesternSahara => Currency::MAD,
+            Country::Mozambique => Currency::MZN,
+            Country::Myanmar => Currency::MMK,
+            Country::Namibia => Currency::NAD,
+            Country::Nepal => Currency::NPR,
+            Country::Nicaragua => Currency::NIO,
+            Country::Nigeria => Currency::NGN,
+            Country::NorthKorea => Currency::KPW,
+            Country::NorthMacedonia => Currency::MKD,
+            Country::Oman => Currency::OMR,
+            Country::Pakistan => Currency::PKR,
+            Country::Panama => Currency::PAB,
+            Country::PapuaNewGuinea => Currency::PGK,
+            Country::Paraguay => Currency::PYG,
+            Country::Peru => Currency::PEN,
+            Country::Philippines => Currency::PHP,
+            Country::Poland => Currency::PLN,
+            Country::Qatar => Currency::QAR,
+            Country::Romania => Currency::RON,
+            Country::RussianFederation => Currency::RUB,
+            Country::Rwanda => Currency::RWF,
+            Country::SaoTomeAndPrincipe => Currency::STD,
+            Country::SaudiArabia => Currency::SAR,
+            Country::Serbia => Currency::RSD,
+            Country::Seychelles => Currency::SCR,
+            Country::SierraLeone => Currency::SLL,
+            Country::Singapore => Currency::SGD,
+            Country::SlovakiaPreEuro => Currency::SKK,
+            Country::SolomonIslands => Currency::SBD,
+            Country::Somalia => Currency::SOS,
+            Country::SouthAfrica => Currency::ZAR,
+            Country::SouthKorea => Currency::KRW,
+            Country::SriLanka => Currency::LKR,
+            Country::Sudan => Currency::SDG,
+            Country::Suriname => Currency::SRD,
+            Country::Swaziland => Currency::SZL,
+            Country::Sweden => Currency::SEK,
+            Country::SyrianArabRepublic => Currency::SYP,
+            Country::Taiwan => Currency::TWD,
+            Country::Tajikistan => Currency::TJS,
+            Country::Tanzania => Currency::TZS,
+            Country::Thailand => Currency::THB,
+            Country::Tonga => Currency::TOP,
+            Country::TrinidadAndTobago => Currency::TTD,
+            Country::Tunisia => Currency::TND,
+            Country::Turkey => Currency::TRY,
+            Country::Turkmenistan => Currency::TMT,
+            Country::Uganda => Currency::UGX,
+            Country::Ukraine => Currency::UAH,
+            Country::UnitedArabEmirates => Currency::AED,
+            Country::Uruguay => Currency::UYU,
+            Country::Uzbekistan => Currency::UZS,
+            Country::Vanuatu => Currency::VUV,
+            Country::Venezuela => Currency::VEF,
+            Country::Vietnam => Currency::VND,
+            Country::Yemen => Currency::YER,
+            Country::Zambia => Currency::ZMK,
+            Country::Zimbabwe => Currency::ZWD,
+            _ => return None,
+        };
+
+        Some(currency)
+    }
+}
+
+string_enum! {
+    pub enum SaleAllocationMethod {
+        /// Average Cost
+        AverageCost = "average",
+        /// Portfolio Default
+        PortfolioDefault = "default",
+        /// First In, First Out
+        FirstInFirstOut = "fifo",
+        /// Last In, First Out
+        LastInFirstOut = "lifo",
+        /// Maximise Gain
+        MaximiseGain = "maximise_cr",
+        /// Minimise Gain
+        MinimiseGain = "minimise_cr",
+        /// Minimise CGT
+        MinimiseCgt = "ss_minimise",
+    }
+}
+
+string_enum! {
+    pub enum CashAccountTransactionTypeName {
+        /// Opening Balance
+        OpeningBalance = "OPENING BALANCE",
+        /// Deposit
+        Deposit = "DEPOSIT",
+        /// Withdrawal
+        Withdrawal = "WITHDRAWAL",
+        /// Interest Payment
+        InterestPayment = "INTEREST_PAYMENT",
+        /// Fee
+        Fee = "FEE",
+        /// Fee Reimbursement
+        FeeReimbursement = "FEE_REIMBURSEMENT",
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct CashAccountTransactionType {
     pub name: CashAccountTransactionTypeName,
 }
@@ -1377,4 +1563,48 @@ mod test {
             Currency::deserialize(StrDeserializer::new("BTC")).unwrap(),
         );
     }
+
+    #[test]
+    fn test_deserialize_unknown_currency_other() {
+        assert_eq!(
+            Currency::BTC,
+            Currency::deserialize(StrDeserializer::new("XBT")).unwrap(),
+        );
+        assert_eq!(
+            Currency::Other("XYZ".to_string()),
+            Currency::deserialize(StrDeserializer::new("XYZ")).unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_market_metadata() {
+        assert_eq!(Some(Country::NewZealand), Market::NZX.country());
+        assert_eq!(Some(Currency::NZD), Market::NZX.currency());
+        assert_eq!(Some("Pacific/Auckland"), Market::NZX.timezone());
+        assert!(!Market::NZX.allows_fractional_quantities());
+        assert!(Market::FundNZ.allows_fractional_quantities());
+        assert_eq!(Some(Currency::AUD), Country::Australia.currency());
+        assert_eq!(Some(0), Currency::JPY.minor_units());
+        assert_eq!(Some("Australian Dollars"), Currency::AUD.name());
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        assert_eq!(Ok(Market::MFund), "mFund".parse());
+        assert!("XYZ".parse::<Market>().is_err());
+        assert_eq!("DIV", PayoutDescription::Dividend.to_string());
+        assert_eq!(
+            Ok(SaleAllocationMethod::FirstInFirstOut),
+            "fifo".parse::<SaleAllocationMethod>()
+        );
+        assert!(Currency::all().all(|c| c.as_str().parse() == Ok(c)));
+    }
+
+    #[test]
+    fn test_round_trip_unknown_market() {
+        let market = Market::deserialize(StrDeserializer::new("XYZ")).unwrap();
+
+        assert_eq!(Market::Other("XYZ".to_string()), market);
+        assert_eq!("\"XYZ\"", serde_json::to_string(&market).unwrap());
+    }
 }
@@ -0,0 +1,78 @@
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+
+/// Fields of a success response that its struct doesn't describe, kept by the
+/// `extra_fields` feature so they survive re-serializing the response.
+pub trait ExtraFields {
+    fn extra_fields(&self) -> &Map<String, Value>;
+
+    fn extra_fields_mut(&mut self) -> &mut Map<String, Value>;
+
+    /// Deserializes the extra field `name`, or `None` if the response didn't have
+    /// it.
+    fn extra_field<T: DeserializeOwned>(&self, name: &str) -> Option<serde_json::Result<T>> {
+        self.extra_fields()
+            .get(name)
+            .map(|value| T::deserialize(value))
+    }
+
+    /// Sets the extra field `name`, returning its previous value.
+    fn set_extra_field<T: serde::Serialize>(
+        &mut self,
+        name: &str,
+        value: T,
+    ) -> serde_json::Result<Option<Value>> {
+        let value = serde_json::to_value(value)?;
+        Ok(self.extra_fields_mut().insert(name.to_string(), value))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[cfg(not(feature = "strict"))]
+    use super::*;
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn test_extra_fields_round_trip() {
+        let mut response = crate::test_data::cash_account();
+        response["links"]["new_link"] = "".into();
+        response["new_field"] = serde_json::json!([1, 2]);
+
+        let mut cash_account: crate::CashAccountShowSuccess =
+            serde_json::from_value(response.clone()).unwrap();
+
+        assert_eq!(
+            Some(vec![1, 2]),
+            cash_account
+                .extra_field::<Vec<i64>>("new_field")
+                .transpose()
+                .unwrap()
+        );
+        assert!(cash_account
+            .extra_field::<String>("new_field")
+            .unwrap()
+            .is_err());
+        assert!(cash_account.extra_field::<bool>("missing").is_none());
+        assert_eq!(response, serde_json::to_value(&cash_account).unwrap());
+
+        cash_account.set_extra_field("flag", true).unwrap();
+        assert_eq!(
+            Some(true),
+            cash_account.extra_field("flag").transpose().unwrap()
+        );
+    }
+
+    #[cfg(feature = "strict")]
+    #[test]
+    fn test_strict_rejects_extra_fields() {
+        let mut response = crate::test_data::cash_account();
+        response["new_field"] = serde_json::json!([1, 2]);
+
+        assert!(serde_json::from_value::<crate::CashAccountShowSuccess>(response.clone()).is_err());
+        assert_eq!(
+            vec!["new_field"],
+            crate::unknown_field_paths::<crate::CashAccountShowSuccess>(response).unwrap()
+        );
+    }
+}
@@ -0,0 +1,344 @@
+use std::{fmt, str::FromStr};
+
+use serde::{
+    de::{self, IgnoredAny, IntoDeserializer, MapAccess, Unexpected, Visitor},
+    ser::SerializeMap,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{
+    ids::CustomGroupId,
+    types_prelude::{string_enum, IdOrName},
+    GroupsListGroupsSuccess, ParseCodeError,
+};
+
+const CUSTOM_GROUP: &str = "custom_group";
+
+string_enum! {
+    /// The groupings built in to Sharesight. The plurals the v2 API accepts, e.g.
+    /// `markets` or `countries`, are parsed too.
+    pub enum BuiltInGrouping {
+        Market = "market" | "markets",
+        Country = "country" | "countries",
+        Currency = "currency" | "currencies",
+        SectorClassification = "sector_classification" | "sector_classifications",
+        IndustryClassification = "industry_classification" | "industry_classifications",
+        InvestmentType = "investment_type" | "investment_types",
+        Portfolio = "portfolio" | "portfolios",
+        Ungrouped = "ungrouped",
+    }
+}
+
+/// How a report groups holdings, sent as the `grouping` parameter and, for a
+/// custom group, the `custom_group_id` parameter.
+///
+/// Parameters flatten it with `#[serde(flatten)]`, so it serializes to both fields,
+/// e.g. `{"grouping": "custom_group", "custom_group_id": 251}`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Grouping {
+    /// One of the groupings built in to Sharesight.
+    BuiltIn(BuiltInGrouping),
+    /// A user defined custom group.
+    Custom(CustomGroupId),
+}
+
+impl Grouping {
+    /// The value of the `grouping` parameter.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Grouping::BuiltIn(grouping) => grouping.as_str(),
+            Grouping::Custom(_) => CUSTOM_GROUP,
+        }
+    }
+
+    /// The value of the `custom_group_id` parameter.
+    pub fn custom_group_id(&self) -> Option<CustomGroupId> {
+        match self {
+            Grouping::Custom(id) => Some(*id),
+            Grouping::BuiltIn(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for Grouping {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Parses a known built in grouping. Custom groups can't be parsed as they need an
+/// id.
+impl FromStr for Grouping {
+    type Err = ParseCodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(Grouping::BuiltIn)
+    }
+}
+
+impl From<BuiltInGrouping> for Grouping {
+    fn from(grouping: BuiltInGrouping) -> Self {
+        Grouping::BuiltIn(grouping)
+    }
+}
+
+impl From<CustomGroupId> for Grouping {
+    fn from(id: CustomGroupId) -> Self {
+        Grouping::Custom(id)
+    }
+}
+
+/// The grouping to request a report with for a group from
+/// [`GroupsList`](crate::GroupsList), whose id is either a custom group's id or the
+/// name of a built in grouping.
+impl From<&GroupsListGroupsSuccess> for Grouping {
+    fn from(group: &GroupsListGroupsSuccess) -> Self {
+        match &group.id {
+            IdOrName::Id(id) => Grouping::Custom(CustomGroupId(*id)),
+            IdOrName::Name(name) => Grouping::BuiltIn(name.as_str().into()),
+        }
+    }
+}
+
+impl Serialize for Grouping {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let custom_group_id = self.custom_group_id();
+
+        let mut map = serializer.serialize_map(Some(1 + custom_group_id.is_some() as usize))?;
+        map.serialize_entry("grouping", self.as_str())?;
+        if let Some(custom_group_id) = custom_group_id {
+            map.serialize_entry("custom_group_id", &custom_group_id)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Grouping {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer
+            .deserialize_map(GroupingVisitor)?
+            .ok_or_else(|| de::Error::missing_field("grouping"))
+    }
+}
+
+/// Deserializes a flattened `Option<Grouping>` parameter, which is `None` only when
+/// neither `grouping` nor `custom_group_id` is present.
+///
+/// `#[serde(flatten)]` on its own turns any error from [`Grouping`] into `None`, so
+/// conflicting fields would be silently dropped.
+pub(crate) fn deserialize_optional_grouping<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Grouping>, D::Error> {
+    deserializer.deserialize_map(GroupingVisitor)
+}
+
+struct GroupingVisitor;
+
+impl<'de> Visitor<'de> for GroupingVisitor {
+    type Value = Option<Grouping>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a map with grouping and optionally custom_group_id")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut grouping = None::<String>;
+        let mut custom_group_id = None::<CustomGroupId>;
+
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "grouping" => grouping = map.next_value()?,
+                "custom_group_id" => custom_group_id = map.next_value()?,
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+
+        match (grouping, custom_group_id) {
+            (None, Some(id)) => Ok(Some(Grouping::Custom(id))),
+            (Some(grouping), Some(id)) if grouping == CUSTOM_GROUP => {
+                Ok(Some(Grouping::Custom(id)))
+            }
+            (Some(grouping), Some(_)) => Err(de::Error::invalid_value(
+                Unexpected::Str(&grouping),
+                &"custom_group alongside custom_group_id",
+            )),
+            (Some(grouping), None) if grouping == CUSTOM_GROUP => {
+                Err(de::Error::missing_field("custom_group_id"))
+            }
+            (Some(grouping), None) => BuiltInGrouping::deserialize(grouping.into_deserializer())
+                .map(|grouping| Some(Grouping::BuiltIn(grouping))),
+            (None, None) => Ok(None),
+        }
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for Grouping {
+    fn schema_name() -> String {
+        "Grouping".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        use schemars::schema::{InstanceType, ObjectValidation, SchemaObject};
+
+        let mut values = BuiltInGrouping::VARIANTS
+            .iter()
+            .map(BuiltInGrouping::as_str)
+            .collect::<Vec<_>>();
+        values.push(CUSTOM_GROUP);
+
+        SchemaObject {
+            instance_type: Some(InstanceType::Object.into()),
+            object: Some(Box::new(ObjectValidation {
+                properties: [
+                    (
+                        "grouping".to_string(),
+                        crate::types_prelude::string_enum_schema(&values),
+                    ),
+                    (
+                        "custom_group_id".to_string(),
+                        gen.subschema_for::<CustomGroupId>(),
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+                required: ["grouping".to_string()].into_iter().collect(),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+/// Only generates known groupings, so that generated values round trip.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Grouping {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        if u.arbitrary()? {
+            Ok(Grouping::Custom(u.arbitrary()?))
+        } else {
+            Ok(Grouping::BuiltIn(u.arbitrary()?))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_serialize_both_fields() {
+        assert_eq!(
+            json!({ "grouping": "custom_group", "custom_group_id": 251 }),
+            serde_json::to_value(Grouping::Custom(CustomGroupId(251))).unwrap()
+        );
+        assert_eq!(
+            json!({ "grouping": "market" }),
+            serde_json::to_value(Grouping::from(BuiltInGrouping::Market)).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_deserialize_conflicting_fields() {
+        let grouping = |json| serde_json::from_value::<Grouping>(json);
+
+        assert_eq!(
+            Grouping::Custom(CustomGroupId(5)),
+            grouping(json!({ "custom_group_id": 5 })).unwrap()
+        );
+        assert!(grouping(json!({ "grouping": "market", "custom_group_id": 5 })).is_err());
+        assert!(grouping(json!({ "grouping": "custom_group" })).is_err());
+        assert_eq!(
+            Grouping::BuiltIn(BuiltInGrouping::Other("region".to_string())),
+            grouping(json!({ "grouping": "region" })).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_built_in_only() {
+        assert_eq!(
+            Grouping::BuiltIn(BuiltInGrouping::Country),
+            "countries".parse().unwrap()
+        );
+        assert!("custom_group".parse::<Grouping>().is_err());
+        assert!("region".parse::<Grouping>().is_err());
+    }
+
+    #[test]
+    fn test_parameters_round_trip() {
+        let parameters = crate::PerformanceShowParameters::new(1.into())
+            .grouping(Grouping::Custom(CustomGroupId(251)));
+        let json = serde_json::to_value(&parameters).unwrap();
+
+        assert_eq!(json!("custom_group"), json["grouping"]);
+        assert_eq!(json!(251), json["custom_group_id"]);
+        assert_eq!(
+            Some(Grouping::Custom(CustomGroupId(251))),
+            serde_json::from_value::<crate::PerformanceShowParameters>(json)
+                .unwrap()
+                .grouping
+        );
+
+        let parameters = crate::v2::ValuationParameters::new(1.into());
+        let json = serde_json::to_value(&parameters).unwrap();
+
+        assert!(json.get("grouping").is_none());
+        assert_eq!(
+            None,
+            serde_json::from_value::<crate::v2::ValuationParameters>(json)
+                .unwrap()
+                .grouping
+        );
+    }
+
+    #[test]
+    fn test_parameters_reject_conflicting_fields() {
+        let parameters = |grouping: serde_json::Value| {
+            let mut json =
+                serde_json::to_value(crate::PerformanceShowParameters::new(1.into())).unwrap();
+            json.as_object_mut()
+                .unwrap()
+                .extend(grouping.as_object().unwrap().clone());
+            serde_json::from_value::<crate::PerformanceShowParameters>(json)
+        };
+
+        assert!(parameters(json!({ "grouping": "market", "custom_group_id": 5 })).is_err());
+        assert!(parameters(json!({ "grouping": "custom_group" })).is_err());
+        assert_eq!(
+            Some(Grouping::BuiltIn(BuiltInGrouping::Market)),
+            parameters(json!({ "grouping": "market" }))
+                .unwrap()
+                .grouping
+        );
+        assert_eq!(None, parameters(json!({})).unwrap().grouping);
+    }
+
+    #[test]
+    fn test_from_groups_list() {
+        let group = |id| {
+            serde_json::from_value::<GroupsListGroupsSuccess>(json!({
+                "id": id,
+                "name": "Group",
+                "custom": false,
+            }))
+            .unwrap()
+        };
+
+        assert_eq!(
+            Grouping::Custom(CustomGroupId(251)),
+            Grouping::from(&group(json!(251)))
+        );
+        assert_eq!(
+            Grouping::BuiltIn(BuiltInGrouping::IndustryClassification),
+            Grouping::from(&group(json!("industry_classification")))
+        );
+        assert_eq!(
+            Grouping::BuiltIn(BuiltInGrouping::Other("region".to_string())),
+            Grouping::from(&group(json!("region")))
+        );
+    }
+}
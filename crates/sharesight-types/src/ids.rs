@@ -0,0 +1,83 @@
+use std::{fmt, num::ParseIntError, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr, PickFirst};
+
+macro_rules! id_types {
+    ($($(#[$meta:meta])* $name:ident;)*) => {
+        $(
+            $(#[$meta])*
+            ///
+            /// Deserializes from either a number or a numeric string, as the API uses both.
+            #[serde_as]
+            #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+            #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+            #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+            #[serde(transparent)]
+            pub struct $name(#[serde_as(as = "PickFirst<(_, DisplayFromStr)>")] pub i64);
+
+            impl From<i64> for $name {
+                fn from(id: i64) -> Self {
+                    Self(id)
+                }
+            }
+
+            impl From<$name> for i64 {
+                fn from(id: $name) -> Self {
+                    id.0
+                }
+            }
+
+            impl fmt::Display for $name {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    self.0.fmt(f)
+                }
+            }
+
+            impl FromStr for $name {
+                type Err = ParseIntError;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    s.parse().map(Self)
+                }
+            }
+        )*
+    };
+}
+
+id_types! {
+    /// The id of a cash account.
+    CashAccountId;
+    /// The id of a transaction in a cash account.
+    CashAccountTransactionId;
+    /// The id of a user defined custom group.
+    CustomGroupId;
+    /// The id of a holding in a portfolio.
+    HoldingId;
+    /// The id of an instrument.
+    InstrumentId;
+    /// The id of a payout.
+    PayoutId;
+    /// The id of a portfolio.
+    PortfolioId;
+    /// The id of a trade.
+    TradeId;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_id_from_number_or_string() {
+        assert_eq!(
+            HoldingId(123),
+            serde_json::from_str::<HoldingId>("123").unwrap()
+        );
+        assert_eq!(
+            HoldingId(123),
+            serde_json::from_str::<HoldingId>("\"123\"").unwrap()
+        );
+        assert_eq!("123", serde_json::to_string(&HoldingId(123)).unwrap());
+    }
+}
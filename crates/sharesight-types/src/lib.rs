@@ -1,17 +1,233 @@
 mod codes;
+#[cfg(feature = "extra_fields")]
+mod extra;
+mod grouping;
+mod ids;
+mod link;
+mod money;
+mod period;
+mod redaction;
+#[cfg(feature = "strict")]
+mod strict;
 mod types;
 mod types_prelude;
 
 pub use codes::*;
+#[cfg(feature = "extra_fields")]
+pub use extra::ExtraFields;
+pub use grouping::{BuiltInGrouping, Grouping};
+pub use ids::*;
+pub use link::Link;
+pub use money::{CurrencyMismatch, ExchangeRate, Money};
+pub use period::{ReportPeriod, ReportPeriodParameters};
+pub use redaction::RedactionPolicy;
+#[cfg(feature = "strict")]
+pub use strict::{unknown_field_paths, UnknownFields};
 pub use types::*;
-pub use types_prelude::{ApiEndpoint, ApiHttpMethod, Number};
+pub use types_prelude::{
+    ApiEndpoint, ApiHttpMethod, ApiUrl, BaseUrl, EndpointInfo, HasApiTransaction, HasLinks, Number,
+    Paginated, ParseBaseUrlError, Scheme,
+};
 
 pub const DEFAULT_API_HOST: &str = "api.sharesight.com";
 
+/// Response fixtures shared by the tests in this crate. `sharesight-model` includes
+/// the same files.
+#[cfg(test)]
+pub(crate) mod test_data {
+    fn fixture(json: &str) -> serde_json::Value {
+        serde_json::from_str(json).unwrap()
+    }
+
+    pub(crate) fn cash_account() -> serde_json::Value {
+        fixture(include_str!("../test-data/cash_account.json"))
+    }
+
+    pub(crate) fn holding_trade() -> serde_json::Value {
+        fixture(include_str!("../test-data/holding_trade.json"))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
     fn it_works() {
         assert_eq!(2 + 2, 4);
     }
+
+    #[test]
+    fn endpoint_registry() {
+        let endpoint = crate::EndpointInfo::find("TradesShow").unwrap();
+
+        assert_eq!("/trades/:id.json", endpoint.url_path);
+        assert_eq!(crate::ApiHttpMethod::Get, endpoint.http_method);
+        assert!(crate::ENDPOINTS.iter().all(|e| crate::ENDPOINTS
+            .iter()
+            .filter(|o| o.name == e.name)
+            .count()
+            == 1));
+    }
+
+    #[test]
+    fn versions_are_generated_side_by_side() {
+        let _: crate::v2::TradesShow = crate::TradesShow;
+
+        assert!(crate::v2::ENDPOINTS.iter().all(|e| e.version == "2.0.0"));
+        assert!(crate::v2_1::ENDPOINTS.iter().all(|e| e.version == "2.1.0"));
+        assert!(crate::v2::ENDPOINTS
+            .iter()
+            .any(|e| e.name == "PortfolioList"));
+        assert_eq!(
+            "/portfolios",
+            <crate::v3::PortfolioList as crate::ApiEndpoint>::URL_PATH
+        );
+    }
+
+    #[test]
+    fn paginated_endpoints_walk_pages() {
+        use crate::{CouponRateList, Paginated};
+
+        let response = |page: Option<&str>| {
+            serde_json::from_value::<crate::CouponRateListSuccess>(serde_json::json!({
+                "coupon_rates": [{ "id": 1, "interest_rate": 10.0, "date": "2023-04-08" }],
+                "pagination": { "page": page, "per_page": 50 },
+                "api_transaction": {
+                    "id": 1,
+                    "version": 3,
+                    "action": "/api/v3/coupon_rates",
+                    "timestamp": "2020-03-03T14:00:00.000Z"
+                }
+            }))
+            .unwrap()
+        };
+
+        let mut parameters = crate::CouponRateListParameters::new(1.into());
+        CouponRateList::set_per_page(&mut parameters, Some(100));
+        assert_eq!(Some(100), CouponRateList::per_page(&parameters));
+
+        assert!(CouponRateList::advance(
+            &mut parameters,
+            &response(Some("exg123456njhuspus=="))
+        ));
+        assert_eq!(
+            Some("exg123456njhuspus=="),
+            CouponRateList::page(&parameters)
+        );
+
+        let last = response(None);
+        assert!(!CouponRateList::advance(&mut parameters, &last));
+        assert_eq!(1, CouponRateList::items(&last).len());
+        assert_eq!(1, CouponRateList::into_items(last)[0].id);
+    }
+
+    #[test]
+    fn endpoints_share_links_and_api_transaction() {
+        use crate::{ApiTransaction, HasApiTransaction, HasLinks, Links};
+
+        fn links<T: HasLinks>(success: &T) -> &Links {
+            success.links().unwrap()
+        }
+
+        let cash_account = serde_json::from_value::<crate::CashAccountShowSuccess>(
+            crate::test_data::cash_account(),
+        )
+        .unwrap();
+        let countries = serde_json::from_value::<crate::CountryListSuccess>(serde_json::json!({
+            "countries": [],
+            "api_transaction": {
+                "id": 1,
+                "version": 3,
+                "action": "/api/v3/countries",
+                "timestamp": "2020-03-03T14:00:00.000Z"
+            },
+            "links": { "self": "https://api.sharesight.com/api/v3/countries" }
+        }))
+        .unwrap();
+
+        assert!(links(&cash_account)
+            .portfolio
+            .as_ref()
+            .is_some_and(|l| l.points_to::<crate::v2::PortfolioShow>()));
+        assert_eq!(None, links(&countries).portfolio);
+        assert_eq!(
+            Some("https://api.sharesight.com/api/v3/countries"),
+            links(&countries).itself.as_ref().map(|l| l.as_str())
+        );
+
+        let transaction: Option<&ApiTransaction> = countries.api_transaction();
+        assert_eq!(Some(1), transaction.map(|t| t.id));
+    }
+
+    #[test]
+    fn success_round_trips_through_serialize() {
+        let trade: crate::HoldingTradesTradesSuccess =
+            serde_json::from_value(crate::test_data::holding_trade()).unwrap();
+        let json = serde_json::to_value(&trade).unwrap();
+
+        assert_eq!(serde_json::json!(123), json["id"]);
+        assert_eq!(serde_json::json!("2024-03-01"), json["transaction_date"]);
+        assert_eq!(
+            json,
+            serde_json::to_value(
+                serde_json::from_value::<crate::HoldingTradesTradesSuccess>(json.clone()).unwrap()
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn null_enum_fields_are_none() {
+        let mut trade = crate::test_data::holding_trade();
+        trade["state"] = serde_json::Value::Null;
+        let trade: crate::HoldingTradesTradesSuccess = serde_json::from_value(trade).unwrap();
+
+        assert_eq!(None, trade.state);
+    }
+
+    #[cfg(feature = "schemars")]
+    #[test]
+    fn schema_includes_docs_and_codes() {
+        let schema =
+            serde_json::to_value(schemars::schema_for!(crate::TradesTradesSuccess)).unwrap();
+
+        assert!(schema["properties"]["quantity"]["description"].is_string());
+        assert_eq!(
+            serde_json::json!("ASX"),
+            schema["definitions"]["Market"]["enum"][1]
+        );
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary_values_round_trip() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        fn round_trips<
+            T: for<'a> Arbitrary<'a> + serde::Serialize + serde::de::DeserializeOwned,
+        >(
+            bytes: &[u8],
+        ) {
+            let value = T::arbitrary(&mut Unstructured::new(bytes)).unwrap();
+            let json = serde_json::to_value(&value).unwrap();
+            let round_tripped = serde_json::from_value::<T>(json.clone()).unwrap();
+
+            assert_eq!(json, serde_json::to_value(&round_tripped).unwrap());
+        }
+
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        for _ in 0..64 {
+            let bytes = (0..1024)
+                .map(|_| {
+                    state ^= state << 13;
+                    state ^= state >> 7;
+                    state ^= state << 17;
+                    state as u8
+                })
+                .collect::<Vec<_>>();
+
+            round_trips::<crate::TradesTradesSuccess>(&bytes);
+            round_trips::<crate::TradesCreateParameters>(&bytes);
+            round_trips::<crate::CashAccountTransactionsListSuccess>(&bytes);
+        }
+    }
 }
@@ -0,0 +1,174 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::types_prelude::{api_version_path, ApiEndpoint};
+
+/// A URL from a response's `links`, such as `links.portfolio`, that can be followed
+/// to fetch the resource it points to.
+///
+/// The API doesn't say which endpoint a link points to, so it's checked against the
+/// endpoint it's followed as with [`Link::points_to`]. Links that aren't absolute
+/// URLs fail to deserialize.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct Link(Url);
+
+impl Link {
+    pub fn new(url: Url) -> Self {
+        Self(url)
+    }
+
+    pub fn parse(url: &str) -> Result<Self, url::ParseError> {
+        Url::parse(url).map(Self)
+    }
+
+    pub fn url(&self) -> &Url {
+        &self.0
+    }
+
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    /// The version segment and path of an API link, e.g. `("v3", "/portfolios/1")`
+    /// for `https://api.sharesight.com/api/v3/portfolios/1?a=b`.
+    pub fn api_path(&self) -> Option<(&str, &str)> {
+        let (_, path) = self.0.path().split_once("/api/")?;
+        let slash = path.find('/')?;
+
+        Some((&path[..slash], &path[slash..]))
+    }
+
+    /// Whether the link is to the version and path of the endpoint `T`, e.g.
+    /// `https://api.sharesight.com/api/v2/portfolios/1` points to
+    /// [`PortfolioShow`](crate::PortfolioShow).
+    pub fn points_to<'a, T: ApiEndpoint<'a>>(&self) -> bool {
+        let Some((version, path)) = self.api_path() else {
+            return false;
+        };
+
+        version == api_version_path(T::VERSION) && path_matches(T::URL_PATH, path)
+    }
+}
+
+/// Whether `path` matches an endpoint's `URL_PATH`, in which parameters are written
+/// as either `:id` or `{id}`. A `.json` extension is optional in both.
+fn path_matches(template: &str, path: &str) -> bool {
+    fn segments(path: &str) -> Vec<&str> {
+        let path = path.strip_suffix(".json").unwrap_or(path);
+        path.split('/').filter(|s| !s.is_empty()).collect()
+    }
+
+    let (template, path) = (segments(template), segments(path));
+
+    template.len() == path.len()
+        && template
+            .iter()
+            .zip(path)
+            .all(|(template, segment)| template.starts_with([':', '{']) || *template == segment)
+}
+
+impl fmt::Display for Link {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Link {
+    type Err = url::ParseError;
+
+    fn from_str(url: &str) -> Result<Self, Self::Err> {
+        Self::parse(url)
+    }
+}
+
+impl From<Url> for Link {
+    fn from(url: Url) -> Self {
+        Self(url)
+    }
+}
+
+impl AsRef<str> for Link {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for Link {
+    fn schema_name() -> String {
+        "Link".to_string()
+    }
+
+    fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        use schemars::schema::{InstanceType, SchemaObject};
+
+        SchemaObject {
+            instance_type: Some(InstanceType::String.into()),
+            format: Some("uri".to_string()),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+/// Generates links to API paths, as arbitrary strings are rarely valid URLs.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Link {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut url = Url::parse("https://api.sharesight.com/api/v3").unwrap();
+        url.path_segments_mut()
+            .unwrap()
+            .extend(u.arbitrary::<Vec<String>>()?);
+
+        Ok(Self(url))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn link(url: &str) -> Link {
+        url.parse().unwrap()
+    }
+
+    #[test]
+    fn test_deserialize_absolute_urls_only() {
+        assert_eq!(
+            link("https://api.sharesight.com/api/v3/portfolios/1"),
+            serde_json::from_str(r#""https://api.sharesight.com/api/v3/portfolios/1""#).unwrap()
+        );
+        assert!(serde_json::from_str::<Link>(r#""/api/v3/portfolios/1""#).is_err());
+        assert!(serde_json::from_str::<Link>(r#""""#).is_err());
+    }
+
+    #[test]
+    fn test_api_path() {
+        assert_eq!(
+            Some(("v3", "/portfolios/1")),
+            link("https://api.sharesight.com/api/v3/portfolios/1?a=b").api_path()
+        );
+        assert_eq!(
+            Some(("v2", "/portfolios/2/diversity.json")),
+            link("http://localhost:3000/prefix/api/v2/portfolios/2/diversity.json").api_path()
+        );
+        assert_eq!(
+            None,
+            link("https://api.sharesight.com/portfolios/1").api_path()
+        );
+    }
+
+    #[test]
+    fn test_points_to() {
+        let portfolio = link("https://api.sharesight.com/api/v2/portfolios/799669676");
+
+        assert!(portfolio.points_to::<crate::v2::PortfolioShow>());
+        assert!(!portfolio.points_to::<crate::v2_1::PortfolioShow>());
+        assert!(!portfolio.points_to::<crate::v2::PortfolioList>());
+        assert!(link("http://localhost:3000/api/v2/trades/29895613.json")
+            .points_to::<crate::v2::TradesShow>());
+    }
+}
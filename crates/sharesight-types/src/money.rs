@@ -0,0 +1,160 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Currency, Number};
+
+/// An amount of money in a specific currency.
+///
+/// Amounts in different currencies can't be combined without an explicit
+/// [`ExchangeRate`], so the arithmetic methods return [`CurrencyMismatch`] instead.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct Money {
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::types_prelude::arbitrary_number))]
+    pub amount: Number,
+    pub currency: Currency,
+}
+
+// `Number` is only `Copy` without the `bigdecimal` feature, so operate on references.
+#[allow(clippy::op_ref)]
+impl Money {
+    pub fn new(amount: Number, currency: Currency) -> Self {
+        Self { amount, currency }
+    }
+
+    pub fn checked_add(&self, other: &Money) -> Result<Money, CurrencyMismatch> {
+        self.check_currency(&other.currency)?;
+
+        Ok(Money::new(
+            &self.amount + &other.amount,
+            self.currency.clone(),
+        ))
+    }
+
+    pub fn checked_sub(&self, other: &Money) -> Result<Money, CurrencyMismatch> {
+        self.check_currency(&other.currency)?;
+
+        Ok(Money::new(
+            &self.amount - &other.amount,
+            self.currency.clone(),
+        ))
+    }
+
+    /// Converts into the rate's `to` currency. Fails unless this money is in the
+    /// rate's `from` currency.
+    pub fn convert(&self, rate: &ExchangeRate) -> Result<Money, CurrencyMismatch> {
+        self.check_currency(&rate.from)?;
+
+        Ok(Money::new(&self.amount * &rate.rate, rate.to.clone()))
+    }
+
+    fn check_currency(&self, currency: &Currency) -> Result<(), CurrencyMismatch> {
+        if &self.currency == currency {
+            Ok(())
+        } else {
+            Err(CurrencyMismatch {
+                expected: self.currency.clone(),
+                found: currency.clone(),
+            })
+        }
+    }
+}
+
+/// The number of units of `to` currency one unit of `from` currency buys.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ExchangeRate {
+    pub from: Currency,
+    pub to: Currency,
+    #[cfg_attr(feature = "arbitrary", arbitrary(with = crate::types_prelude::arbitrary_number))]
+    pub rate: Number,
+}
+
+impl ExchangeRate {
+    pub fn new(from: Currency, to: Currency, rate: Number) -> Self {
+        Self { from, to, rate }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CurrencyMismatch {
+    pub expected: Currency,
+    pub found: Currency,
+}
+
+impl fmt::Display for CurrencyMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "expected an amount in {}, found {}",
+            self.expected.as_str(),
+            self.found.as_str()
+        )
+    }
+}
+
+impl std::error::Error for CurrencyMismatch {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn number(n: i64) -> Number {
+        serde_json::from_value(serde_json::json!(n)).unwrap()
+    }
+
+    #[test]
+    fn test_add_same_currency() {
+        let sum = Money::new(number(1), Currency::AUD)
+            .checked_add(&Money::new(number(2), Currency::AUD))
+            .unwrap();
+
+        assert_eq!(Money::new(number(3), Currency::AUD), sum);
+    }
+
+    #[test]
+    fn test_add_mixed_currencies() {
+        let err = Money::new(number(1), Currency::AUD)
+            .checked_add(&Money::new(number(2), Currency::NZD))
+            .unwrap_err();
+
+        assert_eq!(
+            CurrencyMismatch {
+                expected: Currency::AUD,
+                found: Currency::NZD
+            },
+            err
+        );
+    }
+
+    #[test]
+    fn test_convert() {
+        let rate = ExchangeRate::new(Currency::NZD, Currency::AUD, number(2));
+
+        assert_eq!(
+            Money::new(number(6), Currency::AUD),
+            Money::new(number(3), Currency::NZD).convert(&rate).unwrap()
+        );
+        assert!(Money::new(number(3), Currency::AUD).convert(&rate).is_err());
+    }
+
+    #[test]
+    fn test_symbol_currency_is_not_paired_with_value() {
+        let cash_account: crate::v2::PerformanceCashAccountsSuccess =
+            serde_json::from_value(serde_json::json!({
+                "id": "1",
+                "cash_account_id": 1,
+                "name": "Cash",
+                "value": 10.0,
+                "currency": "AU$",
+                "currency_code": "NZD"
+            }))
+            .unwrap();
+
+        assert_eq!("AU$", cash_account.currency);
+        assert_eq!(Currency::NZD, cash_account.value_money().currency);
+    }
+}
@@ -0,0 +1,277 @@
+use chrono::{Datelike, Days, Months, NaiveDate};
+
+use crate::codes::Country;
+
+/// The dates a report covers, including both the start and end date, as passed in
+/// the `start_date` and `end_date` parameters of reports such as
+/// [`PerformanceShowParameters`](crate::PerformanceShowParameters).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ReportPeriod {
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+}
+
+impl ReportPeriod {
+    pub fn new(start_date: NaiveDate, end_date: NaiveDate) -> Self {
+        Self {
+            start_date,
+            end_date,
+        }
+    }
+
+    /// The calendar quarter `date` is in, e.g. 1 April to 30 June, or `None` if it
+    /// ends after [`NaiveDate::MAX`].
+    pub fn quarter(date: NaiveDate) -> Option<Self> {
+        let start_date = NaiveDate::from_ymd_opt(date.year(), date.month0() / 3 * 3 + 1, 1)?;
+
+        Self::months_from(start_date, 3)
+    }
+
+    pub fn calendar_year(year: i32) -> Option<Self> {
+        Self::financial_year_starting(year, (1, 1))
+    }
+
+    /// The financial year in `country` that ends in `year`, e.g. 1 July 2023 to 30
+    /// June 2024 for Australia in 2024, or `None` if it's out of range.
+    pub fn financial_year(country: &Country, year: i32) -> Option<Self> {
+        let start = country.financial_year_start();
+        let start_year = if start == (1, 1) {
+            year
+        } else {
+            year.checked_sub(1)?
+        };
+
+        Self::financial_year_starting(start_year, start)
+    }
+
+    /// The financial year in `country` that `date` is in.
+    pub fn financial_year_containing(country: &Country, date: NaiveDate) -> Option<Self> {
+        let period = Self::financial_year(country, date.year())?;
+
+        if date > period.end_date {
+            Self::financial_year(country, date.year().checked_add(1)?)
+        } else {
+            Some(period)
+        }
+    }
+
+    /// The `months` months up to and including `end_date`, e.g. 1 July 2023 to 30 June
+    /// 2024 for 12 months ending on 30 June 2024, or `None` if it starts before
+    /// [`NaiveDate::MIN`].
+    pub fn months_ending(end_date: NaiveDate, months: u32) -> Option<Self> {
+        let start_date = end_date
+            .checked_add_days(Days::new(1))?
+            .checked_sub_months(Months::new(months))?;
+
+        Some(Self::new(start_date, end_date))
+    }
+
+    /// The `years` years up to and including `end_date`.
+    pub fn years_ending(end_date: NaiveDate, years: u32) -> Option<Self> {
+        Self::months_ending(end_date, years.checked_mul(12)?)
+    }
+
+    /// The period moved `months` months later, or earlier if negative, keeping
+    /// periods that end on the last day of a month ending on the last day of a month.
+    /// `None` if the moved period is out of range.
+    pub fn shifted_by_months(&self, months: i32) -> Option<Self> {
+        let shift = |date: NaiveDate| {
+            if months < 0 {
+                date.checked_sub_months(Months::new(months.unsigned_abs()))
+            } else {
+                date.checked_add_months(Months::new(months.unsigned_abs()))
+            }
+        };
+        let after_end = self.end_date.succ_opt()?;
+
+        Some(Self::new(
+            shift(self.start_date)?,
+            shift(after_end)?.pred_opt()?,
+        ))
+    }
+
+    /// The period of the same kind immediately before this one, e.g. the previous
+    /// quarter of a quarter. `None` if this period isn't a whole number of months,
+    /// such as 1-10 January, or if the previous one starts before [`NaiveDate::MIN`].
+    pub fn previous(&self) -> Option<Self> {
+        let after_end = self.end_date.succ_opt()?;
+        let months = (after_end.year() - self.start_date.year()) * 12 + after_end.month() as i32
+            - self.start_date.month() as i32;
+        let whole_months = u32::try_from(months)
+            .ok()
+            .filter(|&months| months > 0)
+            .and_then(|months| self.start_date.checked_add_months(Months::new(months)))
+            == Some(after_end);
+        if !whole_months {
+            return None;
+        }
+
+        self.shifted_by_months(-months)
+    }
+
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        self.start_date <= date && date <= self.end_date
+    }
+
+    fn months_from(start_date: NaiveDate, months: u32) -> Option<Self> {
+        let end_date = start_date
+            .checked_add_months(Months::new(months))?
+            .pred_opt()?;
+
+        Some(Self::new(start_date, end_date))
+    }
+
+    fn financial_year_starting(year: i32, (month, day): (u32, u32)) -> Option<Self> {
+        let start_date = NaiveDate::from_ymd_opt(year, month, day)?;
+
+        Self::months_from(start_date, 12)
+    }
+}
+
+impl Country {
+    /// The month and day the financial year for individuals starts on, e.g. 6 April in
+    /// the United Kingdom. Countries other than Australia, New Zealand and the United
+    /// Kingdom use the calendar year.
+    pub fn financial_year_start(&self) -> (u32, u32) {
+        match self {
+            Country::Australia => (7, 1),
+            Country::NewZealand => (4, 1),
+            Country::UnitedKingdom => (4, 6),
+            _ => (1, 1),
+        }
+    }
+}
+
+/// Parameters with a `start_date` and `end_date` that a [`ReportPeriod`] can set.
+pub trait ReportPeriodParameters: Sized {
+    fn set_report_period(&mut self, period: ReportPeriod);
+
+    fn report_period(mut self, period: ReportPeriod) -> Self {
+        self.set_report_period(period);
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_quarters() {
+        let quarter = ReportPeriod::quarter(date(2024, 5, 15)).unwrap();
+
+        assert_eq!(
+            ReportPeriod::new(date(2024, 4, 1), date(2024, 6, 30)),
+            quarter
+        );
+        assert_eq!(
+            ReportPeriod::new(date(2024, 1, 1), date(2024, 3, 31)),
+            quarter.previous().unwrap()
+        );
+        assert_eq!(
+            ReportPeriod::new(date(2023, 10, 1), date(2023, 12, 31)),
+            ReportPeriod::quarter(date(2024, 1, 1))
+                .and_then(|quarter| quarter.previous())
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_financial_years() {
+        let financial_year = |country, year| {
+            let ReportPeriod {
+                start_date,
+                end_date,
+            } = ReportPeriod::financial_year(&country, year).unwrap();
+            (start_date, end_date)
+        };
+
+        assert_eq!(
+            (date(2023, 7, 1), date(2024, 6, 30)),
+            financial_year(Country::Australia, 2024)
+        );
+        assert_eq!(
+            (date(2023, 4, 1), date(2024, 3, 31)),
+            financial_year(Country::NewZealand, 2024)
+        );
+        assert_eq!(
+            (date(2023, 4, 6), date(2024, 4, 5)),
+            financial_year(Country::UnitedKingdom, 2024)
+        );
+        assert_eq!(
+            (date(2024, 1, 1), date(2024, 12, 31)),
+            financial_year(Country::UnitedStates, 2024)
+        );
+        assert_eq!(
+            ReportPeriod::financial_year(&Country::Australia, 2025),
+            ReportPeriod::financial_year_containing(&Country::Australia, date(2024, 7, 1))
+        );
+        assert_eq!(
+            ReportPeriod::financial_year(&Country::UnitedKingdom, 2024),
+            ReportPeriod::financial_year_containing(&Country::UnitedKingdom, date(2024, 4, 5))
+        );
+    }
+
+    #[test]
+    fn test_rolling_periods() {
+        let period = ReportPeriod::years_ending(date(2024, 6, 30), 5).unwrap();
+
+        assert_eq!(
+            ReportPeriod::new(date(2019, 7, 1), date(2024, 6, 30)),
+            period
+        );
+        assert_eq!(
+            ReportPeriod::new(date(2019, 4, 1), date(2024, 3, 31)),
+            period.shifted_by_months(-3).unwrap()
+        );
+        assert_eq!(
+            ReportPeriod::new(date(2023, 3, 1), date(2024, 2, 29)),
+            ReportPeriod::months_ending(date(2024, 2, 29), 12).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_previous_needs_whole_months() {
+        assert_eq!(
+            Some(ReportPeriod::new(date(2023, 12, 1), date(2023, 12, 31))),
+            ReportPeriod::new(date(2024, 1, 1), date(2024, 1, 31)).previous()
+        );
+        assert_eq!(
+            None,
+            ReportPeriod::new(date(2024, 1, 1), date(2024, 1, 10)).previous()
+        );
+        assert_eq!(
+            None,
+            ReportPeriod::new(date(2024, 1, 15), date(2024, 3, 31)).previous()
+        );
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        assert_eq!(
+            None,
+            ReportPeriod::financial_year(&Country::Australia, i32::MAX)
+        );
+        assert_eq!(
+            None,
+            ReportPeriod::financial_year(&Country::Australia, i32::MIN)
+        );
+        assert_eq!(
+            None,
+            ReportPeriod::months_ending(date(2024, 6, 30), u32::MAX)
+        );
+        assert_eq!(
+            None,
+            ReportPeriod::years_ending(date(2024, 6, 30), u32::MAX)
+        );
+        assert_eq!(
+            None,
+            ReportPeriod::quarter(NaiveDate::MIN).and_then(|quarter| quarter.previous())
+        );
+        assert_eq!(None, ReportPeriod::quarter(NaiveDate::MAX));
+    }
+}
@@ -0,0 +1,139 @@
+use url::Url;
+
+/// Controls how much of a request or response ends up in errors and log messages.
+///
+/// Response bodies can contain personal financial data, so by default only their
+/// structure is kept.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RedactionPolicy {
+    /// URLs and response bodies are reported verbatim.
+    Off,
+    /// Query strings are removed from URLs and every value in a JSON response body is
+    /// masked, leaving only the field names.
+    #[default]
+    Fields,
+    /// URLs are reduced to their origin and response bodies are omitted entirely.
+    Full,
+}
+
+impl RedactionPolicy {
+    pub const REDACTED: &'static str = "***";
+
+    pub fn url(&self, url: &Url) -> Url {
+        let mut url = url.clone();
+
+        match self {
+            RedactionPolicy::Off => {}
+            RedactionPolicy::Fields => {
+                url.set_query(None);
+                url.set_fragment(None);
+            }
+            RedactionPolicy::Full => {
+                url.set_path("");
+                url.set_query(None);
+                url.set_fragment(None);
+            }
+        }
+
+        url
+    }
+
+    pub fn body(&self, body: &str) -> String {
+        match self {
+            RedactionPolicy::Off => body.to_string(),
+            RedactionPolicy::Fields => match serde_json::from_str::<serde_json::Value>(body) {
+                Ok(mut value) => {
+                    redact_json_values(&mut value);
+                    value.to_string()
+                }
+                Err(_) => format!("{} ({} bytes)", Self::REDACTED, body.len()),
+            },
+            RedactionPolicy::Full => format!("{} ({} bytes)", Self::REDACTED, body.len()),
+        }
+    }
+
+    /// Mask the message of a json decode error. Data errors quote the value that
+    /// failed, e.g. `invalid type: string "..."`, so only their position is kept;
+    /// syntax errors don't include the input and are returned as is.
+    pub fn json_error(&self, error: serde_json::Error) -> serde_json::Error {
+        use serde_json::error::Category;
+
+        match (self, error.classify()) {
+            (RedactionPolicy::Off, _) | (_, Category::Io | Category::Syntax | Category::Eof) => {
+                error
+            }
+            (_, Category::Data) => serde::de::Error::custom(format_args!(
+                "{} at line {} column {}",
+                Self::REDACTED,
+                error.line(),
+                error.column()
+            )),
+        }
+    }
+}
+
+fn redact_json_values(value: &mut serde_json::Value) {
+    use serde_json::Value;
+
+    match value {
+        Value::Null => {}
+        Value::Bool(_) | Value::Number(_) | Value::String(_) => {
+            *value = Value::String(RedactionPolicy::REDACTED.to_string());
+        }
+        Value::Array(values) => values.iter_mut().for_each(redact_json_values),
+        Value::Object(map) => map.values_mut().for_each(redact_json_values),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn redact_body_fields() {
+        let body = r#"{"portfolios":[{"id":1,"name":"Mine","consolidated":false,"notes":null}]}"#;
+
+        assert_eq!(
+            r#"{"portfolios":[{"consolidated":"***","id":"***","name":"***","notes":null}]}"#,
+            RedactionPolicy::Fields.body(body),
+        );
+        assert_eq!(body, RedactionPolicy::Off.body(body));
+        assert_eq!("*** (73 bytes)", RedactionPolicy::Full.body(body));
+        assert_eq!("*** (9 bytes)", RedactionPolicy::Fields.body("not json!"));
+    }
+
+    #[test]
+    fn redact_url() {
+        let url = Url::parse("https://api.sharesight.com/api/v3/portfolios/1?x=y").unwrap();
+
+        assert_eq!(
+            "https://api.sharesight.com/api/v3/portfolios/1",
+            RedactionPolicy::Fields.url(&url).as_str(),
+        );
+        assert_eq!(
+            "https://api.sharesight.com/",
+            RedactionPolicy::Full.url(&url).as_str(),
+        );
+        assert_eq!(url, RedactionPolicy::Off.url(&url));
+    }
+
+    #[test]
+    fn redact_json_error() {
+        let error = || serde_json::from_str::<u64>(r#""hunter2""#).unwrap_err();
+
+        assert_eq!(
+            "*** at line 1 column 9",
+            RedactionPolicy::Fields.json_error(error()).to_string(),
+        );
+        assert!(RedactionPolicy::Off
+            .json_error(error())
+            .to_string()
+            .contains("hunter2"));
+        assert_eq!(
+            "EOF while parsing a value at line 1 column 0",
+            RedactionPolicy::Full
+                .json_error(serde_json::from_str::<u64>("").unwrap_err())
+                .to_string(),
+        );
+    }
+}
@@ -0,0 +1,110 @@
+use std::cell::Cell;
+use std::collections::BTreeSet;
+
+use serde::de::{DeserializeOwned, Deserializer, Error};
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+thread_local! {
+    /// Set while [`unknown_field_paths`] deserializes a response, so unknown fields
+    /// are kept to be listed rather than rejected.
+    static COLLECTING_UNKNOWN_FIELDS: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Implemented by every success struct, so [`unknown_field_paths`] can find their
+/// unknown fields whichever other features are enabled.
+pub trait UnknownFields {
+    /// Calls `visit` with the extra fields of this struct and of every success struct
+    /// nested in it, along with their paths, e.g. `trades[]` for each of the trades.
+    fn visit_extra_fields(&self, path: &str, visit: &mut dyn FnMut(&str, &Map<String, Value>));
+}
+
+/// Lists the paths of every field in `response` that `T` doesn't describe, e.g.
+/// `trades[].new_field`, so smoke tests can report all of them at once rather than
+/// just the first.
+///
+/// Success structs reject unknown fields with the `strict` feature, so they're
+/// collected instead while `response` is deserialized here. Other errors are
+/// returned as is.
+pub fn unknown_field_paths<T: DeserializeOwned + UnknownFields>(
+    response: Value,
+) -> Result<Vec<String>, serde_json::Error> {
+    struct Collecting(bool);
+
+    impl Drop for Collecting {
+        fn drop(&mut self) {
+            COLLECTING_UNKNOWN_FIELDS.set(self.0);
+        }
+    }
+
+    let response = {
+        let _collecting = Collecting(COLLECTING_UNKNOWN_FIELDS.replace(true));
+        T::deserialize(response)?
+    };
+
+    let mut paths = BTreeSet::new();
+    response.visit_extra_fields("", &mut |path, extra| {
+        paths.extend(extra.keys().map(|name| extra_field_path(path, name)));
+    });
+
+    Ok(paths.into_iter().collect())
+}
+
+/// Deserializes the fields a success struct doesn't describe, failing if there are
+/// any unless they're being collected by [`unknown_field_paths`].
+pub(crate) fn deny_extra_fields<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Map<String, Value>, D::Error> {
+    let extra = Map::deserialize(deserializer)?;
+
+    match extra.keys().next() {
+        Some(name) if !COLLECTING_UNKNOWN_FIELDS.get() => {
+            Err(D::Error::custom(format_args!("unknown field `{}`", name)))
+        }
+        _ => Ok(extra),
+    }
+}
+
+/// The path of the field `name` within the struct at `path`.
+pub(crate) fn extra_field_path(path: &str, name: &str) -> String {
+    if path.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}.{}", path, name)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_unknown_field_paths() {
+        let cash_account = |id: i64| {
+            let mut cash_account = crate::test_data::cash_account();
+            cash_account["id"] = id.into();
+            cash_account["new_field"] = id.into();
+            cash_account
+        };
+        let response = serde_json::json!({
+            "cash_accounts": [cash_account(1), cash_account(2)],
+            "links": { "self": "https://example.com", "new_link": "" },
+            "new_top_level_field": true
+        });
+
+        assert_eq!(
+            vec![
+                "cash_accounts[].new_field",
+                "links.new_link",
+                "new_top_level_field"
+            ],
+            unknown_field_paths::<crate::CashAccountsListSuccess>(response.clone()).unwrap()
+        );
+        assert!(
+            serde_json::from_value::<crate::CashAccountsListSuccess>(response)
+                .unwrap_err()
+                .to_string()
+                .starts_with("unknown field `")
+        );
+    }
+}
@@ -9,6 +9,18 @@ pub use serde::{Deserialize, Serialize};
 pub use serde_with::{serde_as, DefaultOnNull, DisplayFromStr, PickFirst};
 
 pub use crate::codes::*;
+#[cfg(feature = "extra_fields")]
+pub use crate::extra::ExtraFields;
+pub(crate) use crate::grouping::deserialize_optional_grouping;
+pub use crate::grouping::Grouping;
+pub use crate::ids::*;
+pub use crate::link::Link;
+pub use crate::money::Money;
+pub use crate::period::{ReportPeriod, ReportPeriodParameters};
+#[cfg(feature = "strict")]
+pub use crate::strict::UnknownFields;
+#[cfg(feature = "strict")]
+pub(crate) use crate::strict::{deny_extra_fields, extra_field_path};
 
 #[cfg(all(feature = "rust_decimal", not(feature = "bigdecimal")))]
 pub type Number = rust_decimal::Decimal;
@@ -21,6 +33,7 @@ compile_error!(
     "sharesight: Features rust_decimal and bigdecimal are mutually exclusive. Pick one."
 );
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum ApiHttpMethod {
     Get,
     Post,
@@ -29,6 +42,18 @@ pub enum ApiHttpMethod {
     Delete,
 }
 
+impl ApiHttpMethod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ApiHttpMethod::Get => "GET",
+            ApiHttpMethod::Post => "POST",
+            ApiHttpMethod::Patch => "PATCH",
+            ApiHttpMethod::Put => "PUT",
+            ApiHttpMethod::Delete => "DELETE",
+        }
+    }
+}
+
 pub trait ApiEndpoint<'a> {
     const URL_PATH: &'static str;
     const HTTP_METHOD: ApiHttpMethod;
@@ -40,34 +65,276 @@ pub trait ApiEndpoint<'a> {
 
     fn url_path(parameters: &'a Self::Parameters) -> Self::UrlDisplay;
 
-    fn url(api_host: &'a str, parameters: &'a Self::Parameters) -> ApiUrl<'a, Self> {
-        ApiUrl(api_host, parameters, Self::VERSION)
+    fn url(base_url: &'a BaseUrl, parameters: &'a Self::Parameters) -> ApiUrl<'a, Self> {
+        ApiUrl(base_url, parameters, Self::VERSION)
+    }
+}
+
+/// Describes an endpoint at runtime. See [`ENDPOINTS`](crate::ENDPOINTS) for the
+/// current version of every endpoint, or e.g. [`v2::ENDPOINTS`](crate::v2::ENDPOINTS)
+/// for those of one API version.
+#[derive(Clone, Copy, Debug)]
+pub struct EndpointInfo {
+    /// The name of the type implementing [`ApiEndpoint`], e.g. `TradesShow`.
+    pub name: &'static str,
+    pub title: &'static str,
+    /// The section of the API documentation the endpoint is in.
+    pub group: &'static str,
+    pub description: &'static str,
+    pub url_path: &'static str,
+    pub http_method: ApiHttpMethod,
+    pub version: &'static str,
+    /// The JSON schema of the endpoint's parameters.
+    #[cfg(feature = "schemars")]
+    pub parameters_schema: fn() -> schemars::schema::RootSchema,
+}
+
+impl EndpointInfo {
+    /// Finds the current version of the endpoint `name` in [`ENDPOINTS`](crate::ENDPOINTS).
+    pub fn find(name: &str) -> Option<&'static EndpointInfo> {
+        crate::ENDPOINTS
+            .iter()
+            .find(|endpoint| endpoint.name == name)
+    }
+}
+
+/// A success response that describes the API transaction that produced it, so that
+/// logging or auditing can be written once for every endpoint.
+pub trait HasApiTransaction {
+    fn api_transaction(&self) -> Option<&crate::ApiTransaction>;
+}
+
+/// A success response with `links` to itself and related resources, so that link
+/// handling can be written once for every endpoint.
+pub trait HasLinks {
+    fn links(&self) -> Option<&crate::Links>;
+}
+
+/// An endpoint whose results are split into pages.
+///
+/// The API pages with a cursor rather than page numbers, so it doesn't report a
+/// total number of pages. Instead each response has the cursor of the page after
+/// it, which is passed back as the `page` parameter until there are no more pages.
+pub trait Paginated<'a>: ApiEndpoint<'a> {
+    type Item;
+
+    fn page(parameters: &Self::Parameters) -> Option<&str>;
+
+    fn set_page(parameters: &mut Self::Parameters, page: Option<String>);
+
+    fn per_page(parameters: &Self::Parameters) -> Option<i64>;
+
+    fn set_per_page(parameters: &mut Self::Parameters, per_page: Option<i64>);
+
+    /// The cursor of the page after `success`, or `None` if it was the last page.
+    fn next_page(success: &Self::Success) -> Option<&str>;
+
+    fn items(success: &Self::Success) -> &[Self::Item];
+
+    fn into_items(success: Self::Success) -> Vec<Self::Item>;
+
+    /// Points `parameters` at the page after `success`, returning `false` if it was
+    /// the last page.
+    fn advance(parameters: &mut Self::Parameters, success: &Self::Success) -> bool {
+        match Self::next_page(success) {
+            Some(page) => {
+                Self::set_page(parameters, Some(page.to_string()));
+                true
+            }
+            None => false,
+        }
     }
 }
 
-pub struct ApiUrl<'a, T: ApiEndpoint<'a> + ?Sized>(&'a str, &'a T::Parameters, &'a str);
+pub struct ApiUrl<'a, T: ApiEndpoint<'a> + ?Sized>(&'a BaseUrl, &'a T::Parameters, &'a str);
 
 impl<'a, T: ApiEndpoint<'a>> fmt::Display for ApiUrl<'a, T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let Self(api_host, parameters, version) = self;
+        let Self(base_url, parameters, version) = self;
 
-        let version = if version.starts_with("2.1.") {
-            "v2.1"
-        } else if version.starts_with("2.") {
-            "v2"
+        write!(
+            f,
+            "{}/api/{}{}",
+            base_url,
+            api_version_path(version),
+            T::url_path(parameters)
+        )
+    }
+}
+
+/// The version segment of an endpoint's URL, e.g. `v2.1` for version `2.1.0`.
+pub(crate) fn api_version_path(version: &str) -> &'static str {
+    if version.starts_with("2.1.") {
+        "v2.1"
+    } else if version.starts_with("2.") {
+        "v2"
+    } else {
+        "v3"
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Scheme {
+    Http,
+    Https,
+}
+
+impl Scheme {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Scheme::Http => "http",
+            Scheme::Https => "https",
+        }
+    }
+
+    pub fn default_port(&self) -> u16 {
+        match self {
+            Scheme::Http => 80,
+            Scheme::Https => 443,
+        }
+    }
+}
+
+/// The location of a Sharesight API server, used to build both API and
+/// `/oauth2/token` URLs.
+///
+/// It can be parsed from a bare host such as `api.sharesight.com`, which is
+/// assumed to use https, or from a URL such as `http://localhost:8080/sharesight`
+/// to reach a local stub server or a reverse proxy serving the API under a prefix.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BaseUrl {
+    scheme: Scheme,
+    host: String,
+    port: Option<u16>,
+    path_prefix: String,
+}
+
+impl BaseUrl {
+    pub fn new(scheme: Scheme, host: impl Into<String>) -> Self {
+        BaseUrl {
+            scheme,
+            host: host.into(),
+            port: None,
+            path_prefix: String::new(),
+        }
+    }
+
+    pub fn https(host: impl Into<String>) -> Self {
+        Self::new(Scheme::Https, host)
+    }
+
+    pub fn http(host: impl Into<String>) -> Self {
+        Self::new(Scheme::Http, host)
+    }
+
+    pub fn with_port(mut self, port: u16) -> Self {
+        self.port = Some(port);
+        self
+    }
+
+    /// Serve the API from under `path_prefix`, e.g. `/sharesight`.
+    pub fn with_path_prefix(mut self, path_prefix: &str) -> Self {
+        let path_prefix = path_prefix.trim_matches('/');
+        self.path_prefix = if path_prefix.is_empty() {
+            String::new()
         } else {
-            "v3"
+            format!("/{}", path_prefix)
+        };
+        self
+    }
+
+    pub fn scheme(&self) -> Scheme {
+        self.scheme
+    }
+
+    pub fn host(&self) -> &str {
+        &self.host
+    }
+
+    pub fn port(&self) -> Option<u16> {
+        self.port
+    }
+
+    /// The port, or the scheme's default port if none was given.
+    pub fn port_or_known_default(&self) -> u16 {
+        self.port.unwrap_or(self.scheme.default_port())
+    }
+
+    /// The path prefix, either empty or starting with `/` and without a trailing `/`.
+    pub fn path_prefix(&self) -> &str {
+        &self.path_prefix
+    }
+
+    pub fn oauth_token_url(&self) -> String {
+        format!("{}/oauth2/token", self)
+    }
+}
+
+impl Default for BaseUrl {
+    fn default() -> Self {
+        Self::https(crate::DEFAULT_API_HOST)
+    }
+}
+
+impl fmt::Display for BaseUrl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}://{}", self.scheme.as_str(), self.host)?;
+        if let Some(port) = self.port {
+            write!(f, ":{}", port)?;
+        }
+        write!(f, "{}", self.path_prefix)
+    }
+}
+
+impl std::str::FromStr for BaseUrl {
+    type Err = ParseBaseUrlError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (scheme, rest) = match s.split_once("://") {
+            Some(("https", rest)) => (Scheme::Https, rest),
+            Some(("http", rest)) => (Scheme::Http, rest),
+            Some(_) => return Err(ParseBaseUrlError(s.to_string())),
+            None => (Scheme::Https, s),
+        };
+        let (authority, path_prefix) = rest.split_at(rest.find('/').unwrap_or(rest.len()));
+        let (host, port) = match authority.rsplit_once(':') {
+            Some((host, port)) if !authority.ends_with(']') => {
+                let port = port
+                    .parse::<u16>()
+                    .map_err(|_| ParseBaseUrlError(s.to_string()))?;
+                (host, Some(port))
+            }
+            _ => (authority, None),
         };
+
+        if host.is_empty() {
+            return Err(ParseBaseUrlError(s.to_string()));
+        }
+
+        let base_url = Self::new(scheme, host).with_path_prefix(path_prefix);
+
+        Ok(match port {
+            Some(port) => base_url.with_port(port),
+            None => base_url,
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseBaseUrlError(String);
+
+impl fmt::Display for ParseBaseUrlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "https://{}/api/{}{}",
-            api_host,
-            version,
-            T::url_path(parameters)
+            "invalid base url {:?}, expected [http[s]://]host[:port][/path/prefix]",
+            self.0
         )
     }
 }
 
+impl std::error::Error for ParseBaseUrlError {}
+
 pub struct DeserializeDate;
 
 impl<'de> DeserializeAs<'de, NaiveDate> for DeserializeDate {
@@ -91,6 +358,59 @@ impl<T: serde::Serialize> SerializeAs<T> for DeserializeDate {
     }
 }
 
+#[cfg(feature = "schemars")]
+impl serde_with::schemars_0_8::JsonSchemaAs<NaiveDate> for DeserializeDate {
+    fn schema_name() -> String {
+        <NaiveDate as schemars::JsonSchema>::schema_name()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        <NaiveDate as schemars::JsonSchema>::json_schema(gen)
+    }
+}
+
+/// Deserializes an optional value parsed from a string, such as a timestamp or a
+/// link, treating an empty string the same as null.
+pub struct DeserializeOptional;
+
+impl<'de, T> DeserializeAs<'de, Option<T>> for DeserializeOptional
+where
+    T: std::str::FromStr,
+    T::Err: fmt::Display,
+{
+    fn deserialize_as<D>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        match Option::<String>::deserialize(deserializer)? {
+            Some(s) if !s.is_empty() => s.parse().map(Some).map_err(serde::de::Error::custom),
+            _ => Ok(None),
+        }
+    }
+}
+
+impl<T: Serialize> SerializeAs<Option<T>> for DeserializeOptional {
+    fn serialize_as<S>(source: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        source.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl<T: schemars::JsonSchema> serde_with::schemars_0_8::JsonSchemaAs<Option<T>>
+    for DeserializeOptional
+{
+    fn schema_name() -> String {
+        <Option<T> as schemars::JsonSchema>::schema_name()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        <Option<T> as schemars::JsonSchema>::json_schema(gen)
+    }
+}
+
 pub struct DeserializeNumber;
 
 impl<'de> DeserializeAs<'de, Number> for DeserializeNumber {
@@ -111,6 +431,17 @@ impl<T: serde::Serialize> SerializeAs<T> for DeserializeNumber {
     }
 }
 
+#[cfg(feature = "schemars")]
+impl serde_with::schemars_0_8::JsonSchemaAs<Number> for DeserializeNumber {
+    fn schema_name() -> String {
+        <Number as schemars::JsonSchema>::schema_name()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        <Number as schemars::JsonSchema>::json_schema(gen)
+    }
+}
+
 pub struct NumberVisitor;
 
 impl<'de> Visitor<'de> for NumberVisitor {
@@ -148,12 +479,84 @@ impl<'de> Visitor<'de> for NumberVisitor {
         match (v, infinities) {
             ("Infinity", Some((infinity, _))) => Ok(infinity),
             ("-Infinity", Some((_, neg_infinity))) => Ok(neg_infinity),
+            // The decimal types serialize as strings, so they are read back from them.
+            #[cfg(any(feature = "rust_decimal", feature = "bigdecimal"))]
+            _ => v
+                .parse()
+                .map_err(|_| serde::de::Error::invalid_type(Unexpected::Str(v), &self)),
+            #[cfg(not(any(feature = "rust_decimal", feature = "bigdecimal")))]
             _ => Err(serde::de::Error::invalid_type(Unexpected::Str(v), &self)),
         }
     }
 }
 
+/// Generates a finite number with up to four decimal places, which serializes and
+/// deserializes back to the same value whichever `Number` type is used.
+#[cfg(feature = "arbitrary")]
+pub fn arbitrary_number(u: &mut arbitrary::Unstructured) -> arbitrary::Result<Number> {
+    let mantissa: i32 = u.arbitrary()?;
+    let scale: u32 = u.int_in_range(0..=4)?;
+
+    #[cfg(all(feature = "rust_decimal", not(feature = "bigdecimal")))]
+    let number = Number::new(mantissa.into(), scale);
+    #[cfg(all(feature = "bigdecimal", not(feature = "rust_decimal")))]
+    let number = Number::new(mantissa.into(), scale.into());
+    #[cfg(all(not(feature = "rust_decimal"), not(feature = "bigdecimal")))]
+    let number = f64::from(mantissa) / 10f64.powi(scale as i32);
+
+    Ok(number)
+}
+
+/// Generates a date between 1900 and 2099, which the API's date formats can represent.
+#[cfg(feature = "arbitrary")]
+pub fn arbitrary_date(u: &mut arbitrary::Unstructured) -> arbitrary::Result<NaiveDate> {
+    let days = u.int_in_range(0..=73_000)?;
+
+    Ok(NaiveDate::from_ymd_opt(1900, 1, 1).unwrap() + chrono::Days::new(days))
+}
+
+#[cfg(feature = "arbitrary")]
+pub fn arbitrary_date_time(
+    u: &mut arbitrary::Unstructured,
+) -> arbitrary::Result<DateTime<FixedOffset>> {
+    let date = arbitrary_date(u)?;
+    let seconds = u.int_in_range(0..=86_399)?;
+    let offset = FixedOffset::east_opt(u.int_in_range(-56..=56)? * 15 * 60).unwrap();
+    let date_time = date.and_hms_opt(0, 0, 0).unwrap() + chrono::Duration::seconds(seconds);
+
+    Ok(DateTime::from_naive_utc_and_offset(date_time, offset))
+}
+
+#[cfg(feature = "arbitrary")]
+pub fn arbitrary_optional_number(
+    u: &mut arbitrary::Unstructured,
+) -> arbitrary::Result<Option<Number>> {
+    u.arbitrary::<bool>()?
+        .then(|| arbitrary_number(u))
+        .transpose()
+}
+
+#[cfg(feature = "arbitrary")]
+pub fn arbitrary_optional_date(
+    u: &mut arbitrary::Unstructured,
+) -> arbitrary::Result<Option<NaiveDate>> {
+    u.arbitrary::<bool>()?
+        .then(|| arbitrary_date(u))
+        .transpose()
+}
+
+#[cfg(feature = "arbitrary")]
+pub fn arbitrary_optional_date_time(
+    u: &mut arbitrary::Unstructured,
+) -> arbitrary::Result<Option<DateTime<FixedOffset>>> {
+    u.arbitrary::<bool>()?
+        .then(|| arbitrary_date_time(u))
+        .transpose()
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[serde(untagged)]
 pub enum IdOrName {
     Id(i64),
@@ -176,6 +579,169 @@ impl IdOrName {
     }
 }
 
+/// Defines an enum of the string values Sharesight uses for a field, e.g. market
+/// and currency codes or the documented values of a generated field.
+///
+/// Values that are not listed deserialize to `Other` and are logged as a
+/// warning, so a value added by Sharesight doesn't fail the whole response.
+macro_rules! string_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $(
+                $(#[doc = $doc:literal])*
+                $variant:ident = $value:literal $(| $alias:literal)*,
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $(
+                $(#[doc = $doc])*
+                $variant,
+            )*
+            /// A value that was not known when this crate was released.
+            Other(String),
+        }
+
+        impl $name {
+            /// Every known value, in the order Sharesight lists them.
+            pub const VARIANTS: &'static [Self] = &[$(Self::$variant,)*];
+
+            pub fn all() -> impl Iterator<Item = Self> {
+                Self::VARIANTS.iter().cloned()
+            }
+
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(Self::$variant => $value,)*
+                    Self::Other(value) => value,
+                }
+            }
+
+            /// The documented name of the value, or `None` for values this crate
+            /// doesn't know or has no name for.
+            pub fn name(&self) -> Option<&'static str> {
+                let name = match self {
+                    $(Self::$variant => concat!($($doc),*).trim_start(),)*
+                    Self::Other(_) => "",
+                };
+
+                (!name.is_empty()).then_some(name)
+            }
+
+            fn from_known(value: &str) -> Option<Self> {
+                match value {
+                    $($value $(| $alias)* => Some(Self::$variant),)*
+                    _ => None,
+                }
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(value: &str) -> Self {
+                Self::from_known(value).unwrap_or_else(|| Self::Other(value.to_string()))
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(value: String) -> Self {
+                Self::from_known(&value).unwrap_or(Self::Other(value))
+            }
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        /// Parses known values only, so that mistyped input is an error rather than
+        /// an `Other` value.
+        impl ::std::str::FromStr for $name {
+            type Err = $crate::ParseCodeError;
+
+            fn from_str(value: &str) -> Result<Self, Self::Err> {
+                Self::from_known(value).ok_or_else(|| $crate::ParseCodeError {
+                    kind: stringify!($name),
+                    code: value.to_string(),
+                })
+            }
+        }
+
+        #[cfg(feature = "clap")]
+        impl clap::ValueEnum for $name {
+            fn value_variants<'a>() -> &'a [Self] {
+                Self::VARIANTS
+            }
+
+            fn to_possible_value(&self) -> Option<clap::builder::PossibleValue> {
+                let value = match self {
+                    $(Self::$variant => $value,)*
+                    Self::Other(_) => return None,
+                };
+
+                Some(clap::builder::PossibleValue::new(value).help(self.name()))
+            }
+        }
+
+        /// Only known values are generated, as `Other` values may collide with
+        /// them.
+        #[cfg(feature = "arbitrary")]
+        impl<'a> arbitrary::Arbitrary<'a> for $name {
+            fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+                u.choose(Self::VARIANTS).cloned()
+            }
+        }
+
+        /// The schema lists the known values, as that is what input should be
+        /// validated against.
+        #[cfg(feature = "schemars")]
+        impl schemars::JsonSchema for $name {
+            fn schema_name() -> String {
+                stringify!($name).to_string()
+            }
+
+            fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+                $crate::types_prelude::string_enum_schema(&[$($value,)*])
+            }
+        }
+
+        impl ::serde::Serialize for $name {
+            fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> ::serde::Deserialize<'de> for $name {
+            fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+
+                Ok(Self::from_known(&value).unwrap_or_else(|| {
+                    log::warn!("Unknown {} value: {:?}", stringify!($name), value);
+                    Self::Other(value)
+                }))
+            }
+        }
+    };
+}
+
+pub(crate) use string_enum;
+
+/// A string schema limited to `values`, for enums that serialize as strings.
+#[cfg(feature = "schemars")]
+pub(crate) fn string_enum_schema(values: &[&str]) -> schemars::schema::Schema {
+    use schemars::schema::{InstanceType, SchemaObject};
+
+    SchemaObject {
+        instance_type: Some(InstanceType::String.into()),
+        enum_values: Some(values.iter().map(|&value| value.into()).collect()),
+        ..Default::default()
+    }
+    .into()
+}
+
 #[cfg(test)]
 mod id_or_name_tests {
     use super::IdOrName;
@@ -209,3 +775,143 @@ mod id_or_name_tests {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod base_url_tests {
+    use super::{BaseUrl, Scheme};
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            BaseUrl::https("api.sharesight.com"),
+            "api.sharesight.com".parse().unwrap()
+        );
+        assert_eq!(
+            BaseUrl::http("localhost")
+                .with_port(8080)
+                .with_path_prefix("/proxy/sharesight/"),
+            "http://localhost:8080/proxy/sharesight/".parse().unwrap()
+        );
+        assert!("ftp://localhost".parse::<BaseUrl>().is_err());
+        assert!("localhost:http".parse::<BaseUrl>().is_err());
+        assert!("https://".parse::<BaseUrl>().is_err());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            "https://api.sharesight.com/oauth2/token",
+            BaseUrl::default().oauth_token_url()
+        );
+
+        let base_url = BaseUrl::new(Scheme::Http, "127.0.0.1")
+            .with_port(3000)
+            .with_path_prefix("stub");
+        assert_eq!("http://127.0.0.1:3000/stub", base_url.to_string());
+        assert_eq!(
+            "http://127.0.0.1:3000/stub/oauth2/token",
+            base_url.oauth_token_url()
+        );
+    }
+}
+
+#[cfg(test)]
+mod string_enum_tests {
+    string_enum! {
+        pub enum State {
+            Confirmed = "confirmed",
+            Rejected = "rejected",
+        }
+    }
+
+    #[test]
+    fn round_trip_known_and_unknown_values() {
+        assert_eq!(
+            State::Confirmed,
+            serde_json::from_str::<State>("\"confirmed\"").unwrap()
+        );
+        assert_eq!(
+            State::Other("pending".to_string()),
+            serde_json::from_str::<State>("\"pending\"").unwrap()
+        );
+        assert_eq!(
+            "\"pending\"",
+            serde_json::to_string(&State::Other("pending".to_string())).unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_known_values_only() {
+        assert_eq!(Ok(State::Rejected), "rejected".parse());
+        assert!("pending".parse::<State>().is_err());
+        assert_eq!(
+            State::VARIANTS.to_vec(),
+            State::all()
+                .map(|s| s.as_str().parse().unwrap())
+                .collect::<Vec<State>>()
+        );
+        assert_eq!(None, State::Confirmed.name());
+    }
+}
+
+#[cfg(test)]
+mod date_time_tests {
+    use crate::ApiTransaction;
+
+    #[test]
+    fn missing_timestamps_are_none() {
+        let transaction = |timestamp: serde_json::Value| {
+            serde_json::from_value::<ApiTransaction>(serde_json::json!({
+                "id": 1,
+                "version": 3,
+                "action": "/api/v3/portfolios",
+                "timestamp": timestamp,
+            }))
+            .unwrap()
+            .timestamp
+        };
+
+        assert_eq!(None, transaction(serde_json::json!(null)));
+        assert_eq!(None, transaction(serde_json::json!("")));
+        assert_eq!(
+            Some("2020-03-03T14:00:00Z".parse().unwrap()),
+            transaction(serde_json::json!("2020-03-03T14:00:00.000Z"))
+        );
+    }
+}
+
+#[cfg(test)]
+mod number_tests {
+    use super::{DeserializeNumber, Number};
+    use serde_with::DeserializeAs;
+
+    fn number(json: &str) -> Result<Number, serde_json::Error> {
+        DeserializeNumber::deserialize_as(&mut serde_json::Deserializer::from_str(json))
+    }
+
+    #[test]
+    fn deserialize() {
+        assert!(number("1.5").is_ok());
+        assert!(number("\"NaN\"").is_err());
+        assert!(number("\"inf\"").is_err());
+    }
+
+    #[cfg(not(any(feature = "rust_decimal", feature = "bigdecimal")))]
+    #[test]
+    fn float_strings_are_rejected() {
+        assert_eq!(f64::INFINITY, number("\"Infinity\"").unwrap());
+        assert!(number("\"1.5\"").is_err());
+        assert!(number("\"1e400\"").is_err());
+    }
+
+    #[cfg(any(feature = "rust_decimal", feature = "bigdecimal"))]
+    #[test]
+    fn decimal_strings_round_trip() {
+        let value = number("\"1.25\"").unwrap();
+
+        assert_eq!(
+            value,
+            number(&serde_json::to_string(&value).unwrap()).unwrap()
+        );
+    }
+}
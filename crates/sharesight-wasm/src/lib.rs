@@ -0,0 +1,245 @@
+use std::fmt;
+
+use log::warn;
+use serde::{de::DeserializeOwned, Serialize};
+use sharesight_types::{ApiEndpoint, ApiHttpMethod, BaseUrl, RedactionPolicy};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+
+/// Executes sharesight-types endpoints with the `fetch` API of a browser or web worker.
+///
+/// Unlike `sharesight-reqwest`, the client doesn't fetch its own OAuth tokens, as
+/// client secrets can't be kept in a browser. The access token is instead supplied
+/// by the page, typically from its own server.
+pub struct Client {
+    base_url: BaseUrl,
+    access_token: String,
+    redaction: RedactionPolicy,
+}
+
+impl Client {
+    pub fn new(base_url: BaseUrl, access_token: impl Into<String>) -> Self {
+        Client {
+            base_url,
+            access_token: access_token.into(),
+            redaction: RedactionPolicy::default(),
+        }
+    }
+
+    pub fn base_url(&self) -> &BaseUrl {
+        &self.base_url
+    }
+
+    pub fn redaction(&self) -> RedactionPolicy {
+        self.redaction
+    }
+
+    /// Set how much of the request URL and response body is kept in errors and logs.
+    pub fn set_redaction(&mut self, redaction: RedactionPolicy) {
+        self.redaction = redaction;
+    }
+
+    /// Replace the access token, e.g. once the previous one has expired.
+    pub fn set_access_token(&mut self, access_token: impl Into<String>) {
+        self.access_token = access_token.into();
+    }
+
+    pub async fn execute<'a, T: ApiEndpoint<'a>, U: DeserializeOwned>(
+        &'a self,
+        parameters: &'a T::Parameters,
+    ) -> Result<U, SharesightWasmError> {
+        let mut url = T::url(&self.base_url, parameters).to_string();
+
+        let init = web_sys::RequestInit::new();
+        init.set_method(T::HTTP_METHOD.as_str());
+        if T::HTTP_METHOD == ApiHttpMethod::Get {
+            url.push_str(&query_string(T::URL_PATH, parameters)?);
+        } else {
+            init.set_body(&JsValue::from_str(&serde_json::to_string(parameters)?));
+        }
+
+        let request = web_sys::Request::new_with_str_and_init(&url, &init)?;
+        let headers = request.headers();
+        headers.set("Accept", "application/json")?;
+        headers.set("Authorization", &format!("Bearer {}", self.access_token))?;
+        if T::HTTP_METHOD != ApiHttpMethod::Get {
+            headers.set("Content-Type", "application/json")?;
+        }
+
+        let resp: web_sys::Response = JsFuture::from(fetch(&request)).await?.dyn_into()?;
+        let body = JsFuture::from(resp.text()?)
+            .await?
+            .as_string()
+            .unwrap_or_default();
+
+        if resp.ok() {
+            let body = if body.is_empty() { "null" } else { &body };
+
+            Ok(serde_json::from_str(body).map_err(|e| {
+                let e = self.redaction.json_error(e);
+                warn!(
+                    "Error deserializing json: {:?}\n{}",
+                    e,
+                    self.redaction.body(body)
+                );
+                e
+            })?)
+        } else {
+            Err(SharesightWasmError::Http(
+                self.redact_url(&resp.url()),
+                resp.status(),
+                self.redaction.body(&body),
+            ))
+        }
+    }
+
+    /// Redact a URL reported by `fetch`, which is only a string. URLs that can't be
+    /// parsed are dropped unless redaction is off.
+    fn redact_url(&self, url: &str) -> String {
+        match url::Url::parse(url) {
+            Ok(url) => self.redaction.url(&url).to_string(),
+            Err(_) if self.redaction == RedactionPolicy::Off => url.to_string(),
+            Err(_) => RedactionPolicy::REDACTED.to_string(),
+        }
+    }
+}
+
+impl fmt::Debug for Client {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Client")
+            .field("base_url", &self.base_url)
+            .field("redaction", &self.redaction)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Calls `fetch` on whichever global is available, so the client works both in a
+/// page and in a web worker.
+fn fetch(request: &web_sys::Request) -> js_sys::Promise {
+    let global = js_sys::global();
+
+    match global.dyn_ref::<web_sys::Window>() {
+        Some(window) => window.fetch_with_request(request),
+        None => global
+            .unchecked_into::<web_sys::WorkerGlobalScope>()
+            .fetch_with_request(request),
+    }
+}
+
+/// Browsers refuse to send a body with a GET request, so its parameters are sent
+/// as a query string instead, as the reqwest client's json body can't be.
+///
+/// Parameters in `url_path`, such as `:portfolio_id`, are already in the URL so
+/// aren't repeated. Arrays are sent as repeated `name[]` pairs as Rails expects,
+/// and other parameters that aren't strings, numbers or booleans are sent as json.
+fn query_string<T: Serialize>(url_path: &str, parameters: &T) -> Result<String, serde_json::Error> {
+    use serde_json::Value;
+
+    fn scalar(value: Value) -> Option<String> {
+        match value {
+            Value::Null => None,
+            Value::String(value) => Some(value),
+            value => Some(value.to_string()),
+        }
+    }
+
+    let Value::Object(parameters) = serde_json::to_value(parameters)? else {
+        return Ok(String::new());
+    };
+    let url_params = url_path
+        .split('/')
+        .filter_map(|segment| {
+            let segment = segment.strip_suffix(".json").unwrap_or(segment);
+            segment
+                .strip_prefix(':')
+                .or_else(|| segment.strip_prefix('{')?.strip_suffix('}'))
+        })
+        .collect::<Vec<_>>();
+
+    let mut query = form_urlencoded::Serializer::new(String::new());
+    for (name, value) in parameters {
+        if url_params.contains(&name.as_str()) {
+            continue;
+        }
+
+        match value {
+            Value::Array(values) => {
+                let name = format!("{}[]", name);
+                for value in values.into_iter().filter_map(scalar) {
+                    query.append_pair(&name, &value);
+                }
+            }
+            value => {
+                if let Some(value) = scalar(value) {
+                    query.append_pair(&name, &value);
+                }
+            }
+        }
+    }
+
+    let query = query.finish();
+    Ok(if query.is_empty() {
+        query
+    } else {
+        format!("?{}", query)
+    })
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum SharesightWasmError {
+    #[error("Http request returned non-success status code\n{0} {1}\n{2}")]
+    Http(String, u16, String),
+    #[error("Fetch error occurred\n{0}")]
+    Fetch(String),
+    #[error("Deserialize error occurred\n{0:?}")]
+    Deserialize(#[from] serde_json::Error),
+}
+
+impl From<JsValue> for SharesightWasmError {
+    fn from(value: JsValue) -> Self {
+        SharesightWasmError::Fetch(format!("{:?}", value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_parameters_query_string() {
+        use sharesight_types::{ApiEndpoint, PerformanceShow, PortfolioList};
+
+        let parameters = sharesight_types::PerformanceShowParameters::new(1.into())
+            .start_date(chrono::NaiveDate::from_ymd_opt(2024, 4, 1).unwrap())
+            .labels(vec!["a b".to_string(), "c".to_string()]);
+
+        assert_eq!(
+            "?labels%5B%5D=a+b&labels%5B%5D=c&start_date=2024-04-01",
+            query_string(PerformanceShow::URL_PATH, &parameters).unwrap(),
+        );
+        assert_eq!(
+            "",
+            query_string(
+                PortfolioList::URL_PATH,
+                &sharesight_types::PortfolioListParameters::new()
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn redact_url() {
+        let mut client = Client::new(BaseUrl::https("api.sharesight.com"), "token");
+        let url = "https://api.sharesight.com/api/v3/performance?start_date=2024-04-01";
+
+        assert_eq!(
+            "https://api.sharesight.com/api/v3/performance",
+            client.redact_url(url)
+        );
+        assert_eq!("***", client.redact_url("not a url"));
+
+        client.set_redaction(RedactionPolicy::Off);
+        assert_eq!(url, client.redact_url(url));
+        assert_eq!("not a url", client.redact_url("not a url"));
+    }
+}